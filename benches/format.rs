@@ -0,0 +1,21 @@
+#[macro_use]
+extern crate criterion;
+extern crate duration_string;
+
+use criterion::{black_box, Criterion};
+
+use duration_string::batch::format_all;
+use duration_string::{Duration, FormatOptions};
+
+fn bench_format(c: &mut Criterion) {
+    let d = Duration::new(35_344_799);
+    c.bench_function("format_compact", |b| b.iter(|| black_box(d).format_compact()));
+    c.bench_function("display", |b| b.iter(|| black_box(d).to_string()));
+
+    let durations = (0..10_000).map(|secs| Duration::new(secs * 7)).collect::<Vec<Duration>>();
+    let options = FormatOptions::default();
+    c.bench_function("format_all_10k", |b| b.iter(|| format_all(black_box(&durations), &options)));
+}
+
+criterion_group!(benches, bench_format);
+criterion_main!(benches);
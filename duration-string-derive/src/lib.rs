@@ -0,0 +1,73 @@
+extern crate proc_macro;
+extern crate proc_macro2;
+#[macro_use]
+extern crate quote;
+extern crate syn;
+
+use proc_macro::TokenStream;
+use syn::{Data, DeriveInput, Fields, Type};
+
+/// Whether `ty` is a path ending in `Duration`, ex) `std::time::Duration` or
+/// `duration_string::Duration`.
+fn is_duration(ty: &Type) -> bool {
+    match *ty {
+        Type::Path(ref path) => path.path.segments.last().is_some_and(|s| s.ident == "Duration"),
+        _ => false,
+    }
+}
+
+/// Derives a `humanize()` method returning a `<Name>Humanized` struct, with one `String` field
+/// per `Duration` field of the struct, formatted through `duration_string::HumanizeExt`. Fields
+/// of other types are left out.
+///
+/// Fields are recognized by a type path ending in `Duration`. Fields whose type is spelled
+/// otherwise, ex) through an alias, are included by marking them `#[humanize]`.
+#[proc_macro_derive(HumanizeDurations, attributes(humanize))]
+pub fn derive_humanize_durations(input: TokenStream) -> TokenStream {
+    let input = syn::parse_macro_input!(input as DeriveInput);
+    let fields = match input.data {
+        Data::Struct(ref data) => match data.fields {
+            Fields::Named(ref fields) => &fields.named,
+            _ => {
+                return syn::Error::new_spanned(&input.ident, "expected a struct with named fields")
+                    .to_compile_error()
+                    .into()
+            }
+        },
+        _ => {
+            return syn::Error::new_spanned(&input.ident, "expected a struct")
+                .to_compile_error()
+                .into()
+        }
+    };
+
+    let vis = &input.vis;
+    let name = &input.ident;
+    let humanized = syn::Ident::new(&format!("{}Humanized", name), name.span());
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let names = fields
+        .iter()
+        .filter(|field| {
+            is_duration(&field.ty) || field.attrs.iter().any(|attr| attr.path().is_ident("humanize"))
+        })
+        .filter_map(|field| field.ident.as_ref())
+        .collect::<Vec<_>>();
+
+    let expanded = quote! {
+        /// The humanized `Duration` fields of the struct it was derived from.
+        #[derive(Debug, Clone, PartialEq)]
+        #vis struct #humanized {
+            #( pub #names: String, )*
+        }
+
+        impl #impl_generics #name #ty_generics #where_clause {
+            /// Formats every `Duration` field.
+            pub fn humanize(&self) -> #humanized {
+                #humanized {
+                    #( #names: ::duration_string::HumanizeExt::humanize(&self.#names), )*
+                }
+            }
+        }
+    };
+    expanded.into()
+}
@@ -0,0 +1,29 @@
+extern crate duration_string;
+
+use std::time::Duration as StdDuration;
+
+use duration_string::HumanizeDurations;
+
+#[derive(HumanizeDurations)]
+pub struct Metrics {
+    pub name: &'static str,
+    pub uptime: std::time::Duration,
+    #[humanize]
+    pub elapsed: StdDuration,
+    pub timeout: duration_string::Duration,
+}
+
+#[test]
+fn test_derive_humanize() {
+    let metrics = Metrics {
+        name: "api",
+        uptime: StdDuration::from_secs(7199),
+        elapsed: StdDuration::from_secs(90),
+        timeout: duration_string::Duration::new(30),
+    };
+    let humanized = metrics.humanize();
+    assert!(metrics.name == "api");
+    assert!(humanized.uptime == "1 hour, 59 minutes and 59 seconds.");
+    assert!(humanized.elapsed == "1 minute and 30 seconds.");
+    assert!(humanized.timeout == "30 seconds.");
+}
@@ -0,0 +1,47 @@
+//! Localizes a duration with Fluent translation files rather than the built-in locales.
+//!
+//! Run with `cargo run --example fluent --features fluent`.
+
+extern crate duration_string;
+extern crate fluent_bundle;
+
+use duration_string::Duration;
+use fluent_bundle::{FluentBundle, FluentResource};
+
+const EN: &str = "
+build-took = The build took { $hours ->
+        [0] {\"\"}
+        [one] one hour and{\" \"}
+       *[other] { $hours } hours and{\" \"}
+    }{ $minutes ->
+        [one] one minute
+       *[other] { $minutes } minutes
+    }.
+";
+
+const DE: &str = "
+build-took = Der Build dauerte { $hours ->
+        [0] {\"\"}
+        [one] eine Stunde und{\" \"}
+       *[other] { $hours } Stunden und{\" \"}
+    }{ $minutes ->
+        [one] eine Minute
+       *[other] { $minutes } Minuten
+    }.
+";
+
+fn main() {
+    let took: Duration = "1h 25m".parse().unwrap();
+    for (lang, ftl) in [("en-US", EN), ("de", DE)].iter() {
+        let resource = FluentResource::try_new(ftl.to_string()).expect("valid FTL");
+        let mut bundle = FluentBundle::new(vec![lang.parse().expect("valid language tag")]);
+        // Isolation marks only matter when the output is embedded in right-to-left text.
+        bundle.set_use_isolating(false);
+        bundle.add_resource(resource).expect("no duplicate messages");
+
+        let message = bundle.get_message("build-took").and_then(|m| m.value()).unwrap();
+        let mut errors = vec![];
+        let args = took.fluent_args();
+        println!("{}", bundle.format_pattern(message, Some(&args), &mut errors));
+    }
+}
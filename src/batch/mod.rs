@@ -0,0 +1,66 @@
+//! Formatting of many durations at once, ex) for the rows of a report.
+
+use std::io;
+
+#[cfg(feature = "rayon")]
+use rayon::prelude::*;
+
+use duration::{Duration, FormatOptions};
+
+/// Formats each of `durations` according to `options`, ex) `["1h 59m 59s", "1m"]` in
+/// `Style::Compact`.
+pub fn format_all(durations: &[Duration], options: &FormatOptions) -> Vec<String> {
+    durations.iter().map(|d| d.format_with(options)).collect()
+}
+
+/// Like [`format_all`](fn.format_all.html), formatting on the threads of rayon's global pool.
+/// The output keeps the order of `durations`.
+#[cfg(feature = "rayon")]
+pub fn par_format_all(durations: &[Duration], options: &FormatOptions) -> Vec<String> {
+    durations.par_iter().map(|d| d.format_with(options)).collect()
+}
+
+/// Writes each of `durations` according to `options` to `w`, one per line. A single buffer is
+/// reused for every duration, so formatting tens of thousands of rows does not allocate per row.
+pub fn write_all<W: io::Write>(
+    w: &mut W,
+    durations: &[Duration],
+    options: &FormatOptions,
+) -> io::Result<()> {
+    let mut line = String::new();
+    for d in durations {
+        line.clear();
+        // Writing to a `String` cannot fail.
+        let _ = d.write_with(&mut line, options);
+        line.push('\n');
+        w.write_all(line.as_bytes())?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use batch::{format_all, write_all};
+    use duration::{Duration, FormatOptions, Style};
+
+    #[test]
+    fn test_format_all() {
+        let durations = [Duration::new(7199), Duration::new(60), Duration::ZERO];
+        let options = FormatOptions { style: Style::Compact, ..FormatOptions::default() };
+        assert!(format_all(&durations, &options) == ["1h 59m 59s", "1m", "0s"]);
+
+        let mut out = Vec::new();
+        write_all(&mut out, &durations, &options).unwrap();
+        assert!(out == b"1h 59m 59s\n1m\n0s\n");
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn test_par_format_all() {
+        use batch::par_format_all;
+
+        let durations = (0..10_000).map(Duration::new).collect::<Vec<Duration>>();
+        let options = FormatOptions::default();
+        assert!(par_format_all(&durations, &options) == format_all(&durations, &options));
+    }
+}
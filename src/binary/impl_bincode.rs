@@ -0,0 +1,56 @@
+use bincode::de::read::Reader;
+use bincode::de::{Decode, Decoder};
+use bincode::enc::write::Writer;
+use bincode::enc::{Encode, Encoder};
+use bincode::error::{DecodeError, EncodeError};
+
+use binary::decode_with;
+use duration::Duration;
+
+impl Encode for Duration {
+    fn encode<E: Encoder>(&self, encoder: &mut E) -> Result<(), EncodeError> {
+        encoder.writer().write(&self.to_binary())
+    }
+}
+
+impl<Context> Decode<Context> for Duration {
+    fn decode<D: Decoder<Context = Context>>(decoder: &mut D) -> Result<Self, DecodeError> {
+        let mut read_err = None;
+        let decoded = decode_with(|| {
+            let mut byte = [0];
+            match decoder.reader().read(&mut byte) {
+                Ok(()) => Some(byte[0]),
+                Err(e) => {
+                    read_err = Some(e);
+                    None
+                }
+            }
+        });
+        match (decoded, read_err) {
+            (_, Some(e)) => Err(e),
+            (Ok(d), None) => Ok(d),
+            (Err(e), None) => Err(DecodeError::OtherString(e.to_string())),
+        }
+    }
+}
+
+impl_borrow_decode!(Duration);
+
+#[cfg(test)]
+mod tests {
+    use bincode::config;
+
+    use duration::Duration;
+
+    #[test]
+    fn test_bincode_round_trip() {
+        let durations = vec![Duration::new(5), Duration::new(86_400)];
+        let bytes = bincode::encode_to_vec(&durations, config::standard()).unwrap();
+        assert!(bytes == [2, 10, 0x80, 0xc6, 0x0a]);
+
+        let (decoded, len): (Vec<Duration>, usize) =
+            bincode::decode_from_slice(&bytes, config::standard()).unwrap();
+        assert!(decoded == durations && len == bytes.len());
+        assert!(bincode::decode_from_slice::<Duration, _>(&[0x80], config::standard()).is_err());
+    }
+}
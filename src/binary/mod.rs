@@ -0,0 +1,104 @@
+//! A compact binary encoding of [`Duration`](../duration/struct.Duration.html) for wire
+//! protocols and flash storage. With the `bincode` feature, `Duration` implements bincode's
+//! `Encode` and `Decode` in this encoding. Serde based formats such as postcard already encode
+//! [`serde::seconds`](../serde/seconds/index.html) as a varint.
+//!
+//! The encoding is the LEB128 varint of `seconds << 1 | has_nanos`, followed, when `has_nanos` is
+//! set, by the LEB128 varint of a sub-second part in nanoseconds below 1,000,000,000. So
+//! durations below 64 seconds take one byte and a day takes three. As a `Duration` counts whole
+//! seconds, encoding never sets `has_nanos`, while decoding accepts and truncates nanoseconds
+//! written by other implementations.
+
+use duration::Duration;
+use error::Error;
+
+#[cfg(feature = "bincode")]
+mod impl_bincode;
+
+/// The most bytes a varint of `seconds << 1` takes.
+const MAX_VARINT_LEN: usize = 10;
+
+fn write_varint(mut n: u128, out: &mut Vec<u8>) {
+    while n >= 0x80 {
+        out.push((n as u8 & 0x7f) | 0x80);
+        n >>= 7;
+    }
+    out.push(n as u8);
+}
+
+fn read_varint<F: FnMut() -> Option<u8>>(next: &mut F) -> Result<u128, Error> {
+    let mut n: u128 = 0;
+    for i in 0..MAX_VARINT_LEN {
+        let byte = next().ok_or(Error::InvalidFormat)?;
+        n |= u128::from(byte & 0x7f) << (7 * i);
+        if byte & 0x80 == 0 {
+            return Ok(n);
+        }
+    }
+    Err(Error::Overflow)
+}
+
+/// Decodes one duration from the bytes yielded by `next`.
+pub(crate) fn decode_with<F: FnMut() -> Option<u8>>(mut next: F) -> Result<Duration, Error> {
+    let header = read_varint(&mut next)?;
+    if header & 1 == 1 && read_varint(&mut next)? >= 1_000_000_000 {
+        return Err(Error::InvalidFormat);
+    }
+    let secs = header >> 1;
+    if secs > usize::MAX as u128 {
+        return Err(Error::Overflow);
+    }
+    Ok(Duration::new(secs as usize))
+}
+
+impl Duration {
+    /// Appends the encoding of `self` to `out`.
+    pub fn encode_binary(&self, out: &mut Vec<u8>) {
+        write_varint((self.total_seconds() as u128) << 1, out);
+    }
+
+    pub fn to_binary(&self) -> Vec<u8> {
+        let mut out = Vec::new();
+        self.encode_binary(&mut out);
+        out
+    }
+
+    /// Decodes a duration from the start of `bytes`, returning it along with the amount of bytes
+    /// read. Truncated input and nanoseconds of a second or more are an invalid format.
+    pub fn decode_binary(bytes: &[u8]) -> Result<(Duration, usize), Error> {
+        let mut read = 0;
+        let d = decode_with(|| {
+            let byte = bytes.get(read).cloned();
+            read += 1;
+            byte
+        })?;
+        Ok((d, read))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use duration::Duration;
+    use error::Error;
+
+    #[test]
+    fn test_binary_round_trip() {
+        for &secs in &[0, 1, 63, 64, 86_400, usize::MAX] {
+            let bytes = Duration::new(secs).to_binary();
+            assert!(Duration::decode_binary(&bytes) == Ok((Duration::new(secs), bytes.len())));
+        }
+        assert!(Duration::new(63).to_binary() == [126]);
+        assert!(Duration::new(86_400).to_binary().len() == 3);
+    }
+
+    #[test]
+    fn test_binary_nanos_and_errors() {
+        // 1 second with 500ms, then a trailing byte which is not read.
+        let bytes = [0b11, 0x80, 0xca, 0xb5, 0xee, 0x01, 0xff];
+        assert!(Duration::decode_binary(&bytes) == Ok((Duration::new(1), 6)));
+        assert!(Duration::decode_binary(&[]) == Err(Error::InvalidFormat));
+        assert!(Duration::decode_binary(&[0x80]) == Err(Error::InvalidFormat));
+        assert!(Duration::decode_binary(&[0xff; 11]) == Err(Error::Overflow));
+        assert!(Duration::decode_binary(&[0b11, 0x80, 0x94, 0xeb, 0xdc, 0x03]) == Err(Error::InvalidFormat));
+    }
+}
@@ -0,0 +1,205 @@
+use std::fmt;
+
+use business::Date;
+use duration::Duration;
+use error::Error;
+use join::{join_human, JoinOptions};
+
+/// Days in a quarter of 13 weeks, as in 4-4-5 fiscal calendars.
+const QUARTER_DAYS: usize = 91;
+
+/// A fiscal year beginning on the first day of `start_month`, ex) October for the US federal
+/// government. Defaults to the calendar year.
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub struct FiscalYear {
+    start_month: u32,
+}
+
+impl Default for FiscalYear {
+    fn default() -> Self {
+        FiscalYear { start_month: 1 }
+    }
+}
+
+/// A quarter of a [`FiscalYear`](struct.FiscalYear.html), ex) "Q1 FY2025". Fiscal years are
+/// named after the calendar year they end in.
+#[derive(PartialEq, Eq, PartialOrd, Ord, Clone, Copy, Debug)]
+pub struct FiscalQuarter {
+    pub year: i32,
+    /// From 1 to 4.
+    pub quarter: u32,
+}
+
+impl FiscalQuarter {
+    pub fn next(&self) -> FiscalQuarter {
+        if self.quarter == 4 {
+            FiscalQuarter {
+                year: self.year + 1,
+                quarter: 1,
+            }
+        } else {
+            FiscalQuarter {
+                year: self.year,
+                quarter: self.quarter + 1,
+            }
+        }
+    }
+}
+
+impl fmt::Display for FiscalQuarter {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Q{} FY{}", self.quarter, self.year)
+    }
+}
+
+impl FiscalYear {
+    /// A fiscal year starting in `start_month`, from 1 to 12. Other months are an
+    /// [`Error::InvalidFormat`](../error/enum.Error.html).
+    pub fn new(start_month: u32) -> Result<Self, Error> {
+        if !(1..=12).contains(&start_month) {
+            return Err(Error::InvalidFormat);
+        }
+        Ok(FiscalYear { start_month })
+    }
+
+    /// The fiscal quarter `date` falls in, ex) 2024-11-15 is in Q1 FY2025 for a fiscal year
+    /// starting in October.
+    pub fn quarter_of(&self, date: Date) -> FiscalQuarter {
+        let offset = (date.month + 12 - self.start_month) % 12;
+        let ends_next_year = self.start_month > 1 && date.month >= self.start_month;
+        FiscalQuarter {
+            year: date.year + if ends_next_year { 1 } else { 0 },
+            quarter: offset / 3 + 1,
+        }
+    }
+
+    /// The first day of `quarter`.
+    pub fn quarter_start(&self, quarter: FiscalQuarter) -> Date {
+        let first_year = quarter.year - if self.start_month > 1 { 1 } else { 0 };
+        let month = self.start_month - 1 + (quarter.quarter - 1) * 3;
+        Date::new(first_year + (month / 12) as i32, month % 12 + 1, 1)
+    }
+}
+
+/// An age in quarters and leftover days, ex) "2 quarters and 15 days.".
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub struct QuarterDuration {
+    pub quarters: usize,
+    pub days: usize,
+}
+
+impl QuarterDuration {
+    /// Counts the fiscal quarters of `fiscal_year` lying entirely from `start` to `end`, along
+    /// with the days before the first and after the last of them. Zero if `end` is not after
+    /// `start`.
+    pub fn between(start: Date, end: Date, fiscal_year: &FiscalYear) -> Self {
+        let days = |a: Date, b: Date| (b.days_since_epoch() - a.days_since_epoch()) as usize;
+        if end <= start {
+            return QuarterDuration { quarters: 0, days: 0 };
+        }
+
+        let mut quarter = fiscal_year.quarter_of(start);
+        if fiscal_year.quarter_start(quarter) < start {
+            quarter = quarter.next();
+        }
+        let first = fiscal_year.quarter_start(quarter);
+        if first > end {
+            return QuarterDuration {
+                quarters: 0,
+                days: days(start, end),
+            };
+        }
+
+        let mut quarters = 0;
+        let mut last = first;
+        while fiscal_year.quarter_start(quarter.next()) <= end {
+            quarter = quarter.next();
+            last = fiscal_year.quarter_start(quarter);
+            quarters += 1;
+        }
+        QuarterDuration {
+            quarters,
+            days: days(start, first) + days(last, end),
+        }
+    }
+}
+
+impl fmt::Display for QuarterDuration {
+    /// Formats like [`Duration`](../duration/struct.Duration.html), ex) "1 quarter and 1 day.".
+    /// Zero formats as "0 days.".
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let plural = |n: usize| if n != 1 { "s" } else { "" };
+        let mut parts = Vec::new();
+        if self.quarters > 0 {
+            parts.push(format!("{} quarter{}", self.quarters, plural(self.quarters)));
+        }
+        if self.days > 0 || self.quarters == 0 {
+            parts.push(format!("{} day{}", self.days, plural(self.days)));
+        }
+        f.write_str(&join_human(parts, &JoinOptions::default()))
+    }
+}
+
+impl Duration {
+    /// Expresses `self` in quarters of 13 weeks and leftover days, ex) 197 days -> "2 quarters
+    /// and 15 days.". Units below a day are dropped. Use
+    /// [`QuarterDuration::between`](../business/struct.QuarterDuration.html#method.between) to
+    /// count calendar quarters of a fiscal year instead.
+    pub fn to_quarters(&self) -> QuarterDuration {
+        let days = self.total_seconds() / (60 * 60 * 24);
+        QuarterDuration {
+            quarters: days / QUARTER_DAYS,
+            days: days % QUARTER_DAYS,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use business::{Date, FiscalQuarter, FiscalYear, QuarterDuration};
+    use duration::Duration;
+    use error::Error;
+
+    #[test]
+    fn test_quarter_of() {
+        let us_federal = FiscalYear::new(10).unwrap();
+        let q1 = FiscalQuarter { year: 2025, quarter: 1 };
+        assert!(us_federal.quarter_of(Date::new(2024, 11, 15)) == q1);
+        assert!(us_federal.quarter_of(Date::new(2025, 9, 30)).to_string() == "Q4 FY2025");
+        assert!(FiscalYear::default().quarter_of(Date::new(2025, 4, 1)).to_string() == "Q2 FY2025");
+
+        let q = FiscalQuarter { year: 2025, quarter: 2 };
+        assert!(us_federal.quarter_start(q) == Date::new(2025, 1, 1));
+        assert!(FiscalYear::new(4).unwrap().quarter_start(q) == Date::new(2024, 7, 1));
+    }
+
+    #[test]
+    fn test_fiscal_year_new() {
+        assert!(FiscalYear::new(1) == Ok(FiscalYear::default()));
+        assert!(FiscalYear::new(0) == Err(Error::InvalidFormat));
+        assert!(FiscalYear::new(13) == Err(Error::InvalidFormat));
+    }
+
+    #[test]
+    fn test_quarters_between() {
+        let calendar = FiscalYear::default();
+        let (start, end) = (Date::new(2024, 12, 17), Date::new(2025, 7, 1));
+        let age = QuarterDuration::between(start, end, &calendar);
+        assert!(age == QuarterDuration { quarters: 2, days: 15 });
+        assert!(age.to_string() == "2 quarters and 15 days.");
+
+        let age = QuarterDuration::between(Date::new(2025, 2, 1), Date::new(2025, 3, 1), &calendar);
+        assert!(age.to_string() == "28 days.");
+        let february = FiscalYear::new(2).unwrap();
+        let age = QuarterDuration::between(Date::new(2025, 1, 1), Date::new(2025, 4, 1), &february);
+        assert!(age == QuarterDuration { quarters: 0, days: 90 });
+    }
+
+    #[test]
+    fn test_to_quarters() {
+        let d = Duration::new(197 * 86_400 + 3600);
+        assert!(d.to_quarters().to_string() == "2 quarters and 15 days.");
+        assert!(Duration::new(91 * 86_400).to_quarters().to_string() == "1 quarter.");
+        assert!(Duration::new(0).to_quarters().to_string() == "0 days.");
+    }
+}
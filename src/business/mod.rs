@@ -0,0 +1,401 @@
+use std::fmt;
+use std::time::SystemTime;
+
+use calendar::unix_secs;
+use duration::{Duration, TimeUnit};
+use error::Error;
+use join::{join_human, JoinOptions};
+
+mod fiscal;
+
+pub use self::fiscal::{FiscalQuarter, FiscalYear, QuarterDuration};
+
+/// A day of the week.
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub enum Weekday {
+    Monday,
+    Tuesday,
+    Wednesday,
+    Thursday,
+    Friday,
+    Saturday,
+    Sunday,
+}
+
+pub(crate) const WEEKDAYS: [Weekday; 7] = [
+    Weekday::Monday,
+    Weekday::Tuesday,
+    Weekday::Wednesday,
+    Weekday::Thursday,
+    Weekday::Friday,
+    Weekday::Saturday,
+    Weekday::Sunday,
+];
+
+/// A proleptic Gregorian calendar date.
+#[derive(PartialEq, Eq, PartialOrd, Ord, Clone, Copy, Debug)]
+pub struct Date {
+    pub year: i32,
+    pub month: u32,
+    pub day: u32,
+}
+
+impl Date {
+    pub fn new(year: i32, month: u32, day: u32) -> Self {
+        Date { year, month, day }
+    }
+
+    /// Builds the date lying `days` days after 1970-01-01.
+    pub fn from_days_since_epoch(days: i64) -> Self {
+        // See http://howardhinnant.github.io/date_algorithms.html#civil_from_days
+        let z = days + 719_468;
+        let era = z.div_euclid(146_097);
+        let doe = z.rem_euclid(146_097);
+        let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+        let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+        let mp = (5 * doy + 2) / 153;
+        let day = (doy - (153 * mp + 2) / 5 + 1) as u32;
+        let month = if mp < 10 { mp + 3 } else { mp - 9 } as u32;
+        let year = (yoe + era * 400 + if month <= 2 { 1 } else { 0 }) as i32;
+        Date { year, month, day }
+    }
+
+    /// The amount of days from 1970-01-01 to `self`.
+    pub fn days_since_epoch(&self) -> i64 {
+        // See http://howardhinnant.github.io/date_algorithms.html#days_from_civil
+        let year = i64::from(self.year) - if self.month <= 2 { 1 } else { 0 };
+        let era = year.div_euclid(400);
+        let yoe = year.rem_euclid(400);
+        let month = i64::from(self.month);
+        let mp = if month > 2 { month - 3 } else { month + 9 };
+        let doy = (153 * mp + 2) / 5 + i64::from(self.day) - 1;
+        let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+        era * 146_097 + doe - 719_468
+    }
+
+    pub fn weekday(&self) -> Weekday {
+        WEEKDAYS[self.weekday_index() as usize]
+    }
+
+    /// Days since the Monday of the week, 1970-01-01 being a Thursday.
+    fn weekday_index(&self) -> i64 {
+        (self.days_since_epoch() + 3).rem_euclid(7)
+    }
+
+    /// The ISO 8601 week-numbering year and week of `self`, ex) `(2021, 52)` for 2022-01-01.
+    /// Weeks start on Monday, and the first week of a year holds its first Thursday.
+    pub fn iso_week(&self) -> (i32, u32) {
+        let thursday = self.days_since_epoch() - self.weekday_index() + 3;
+        let year = Date::from_days_since_epoch(thursday).year;
+        let week = (thursday - Date::new(year, 1, 1).days_since_epoch()) / 7 + 1;
+        (year, week as u32)
+    }
+}
+
+/// Regional conventions for weekends and the first day of the week.
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub enum Region {
+    /// Saturday and Sunday weekend, weeks start on Monday.
+    Europe,
+    /// Saturday and Sunday weekend, weeks start on Sunday.
+    NorthAmerica,
+    /// Friday and Saturday weekend, weeks start on Sunday.
+    MiddleEast,
+}
+
+/// Describes working time: how many hours make up a working day and when it starts, which
+/// weekdays are weekend days, and which dates are holidays. Defaults to 8 hour days starting at
+/// 9:00 with a Saturday and Sunday weekend and weeks starting on Monday.
+#[derive(PartialEq, Clone, Debug)]
+pub struct BusinessCalendar {
+    hours_per_day: usize,
+    day_start: usize,
+    weekend: Vec<Weekday>,
+    first_day: Weekday,
+    holidays: Vec<Date>,
+}
+
+impl Default for BusinessCalendar {
+    fn default() -> Self {
+        BusinessCalendar::with_hours(8)
+    }
+}
+
+impl BusinessCalendar {
+    /// A calendar with `hours_per_day` working hours per day and a Saturday and Sunday weekend.
+    /// Hours outside 1 to 24 are an [`Error::InvalidFormat`](../error/enum.Error.html).
+    pub fn new(hours_per_day: usize) -> Result<Self, Error> {
+        if !(1..=24).contains(&hours_per_day) {
+            return Err(Error::InvalidFormat);
+        }
+        Ok(BusinessCalendar::with_hours(hours_per_day))
+    }
+
+    fn with_hours(hours_per_day: usize) -> Self {
+        BusinessCalendar {
+            hours_per_day,
+            day_start: 9 * 60 * 60,
+            weekend: vec![Weekday::Saturday, Weekday::Sunday],
+            first_day: Weekday::Monday,
+            holidays: Vec::new(),
+        }
+    }
+
+    /// A calendar with 8 hour days following the conventions of `region`.
+    pub fn preset(region: Region) -> Self {
+        let calendar = BusinessCalendar::with_hours(8);
+        match region {
+            Region::Europe => calendar,
+            Region::NorthAmerica => calendar.first_day(Weekday::Sunday),
+            Region::MiddleEast => calendar
+                .weekend(&[Weekday::Friday, Weekday::Saturday])
+                .first_day(Weekday::Sunday),
+        }
+    }
+
+    /// Sets the time of day at which working hours start, in seconds after midnight UTC.
+    pub fn day_start(mut self, secs: usize) -> Self {
+        self.day_start = secs;
+        self
+    }
+
+    /// Sets the first day of the week.
+    pub fn first_day(mut self, day: Weekday) -> Self {
+        self.first_day = day;
+        self
+    }
+
+    /// Replaces the weekend days.
+    pub fn weekend(mut self, days: &[Weekday]) -> Self {
+        self.weekend = days.to_vec();
+        self
+    }
+
+    /// Adds a holiday.
+    pub fn holiday(mut self, date: Date) -> Self {
+        self.holidays.push(date);
+        self
+    }
+
+    /// Adds every holiday yielded by `dates`.
+    pub fn holidays<I: IntoIterator<Item = Date>>(mut self, dates: I) -> Self {
+        self.holidays.extend(dates);
+        self
+    }
+
+    pub fn hours_per_day(&self) -> usize {
+        self.hours_per_day
+    }
+
+    /// The first day of the week containing `date`.
+    pub fn week_start(&self, date: Date) -> Date {
+        let index = |day| WEEKDAYS.iter().position(|&d| d == day).unwrap_or(0) as i64;
+        let offset = (index(date.weekday()) - index(self.first_day)).rem_euclid(7);
+        Date::from_days_since_epoch(date.days_since_epoch() - offset)
+    }
+
+    /// Whether `date` is neither a weekend day nor a holiday.
+    pub fn is_working_day(&self, date: Date) -> bool {
+        !self.weekend.contains(&date.weekday()) && !self.holidays.contains(&date)
+    }
+}
+
+/// An amount of working time expressed in business days of a
+/// [`BusinessCalendar`](struct.BusinessCalendar.html) and the remaining hours, minutes and
+/// seconds.
+#[derive(PartialEq, Clone, Copy)]
+pub struct BusinessDuration {
+    pub days: usize,
+    /// The working time left over after whole business days. Never spans a whole business day.
+    pub remainder: Duration,
+}
+
+impl fmt::Display for BusinessDuration {
+    /// Formats like [`Duration`](../duration/struct.Duration.html), with business days in place of
+    /// days, ex) "2 business days and 3 hours.". Zero formats as "0 business days.".
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        let mut parts = Vec::new();
+        if self.days > 0 {
+            let plural = if self.days > 1 { "s" } else { "" };
+            parts.push(format!("{} business day{}", self.days, plural));
+        }
+        let units: [&TimeUnit; 3] = [
+            &self.remainder.hours,
+            &self.remainder.minutes,
+            &self.remainder.seconds,
+        ];
+        parts.extend(units.iter().filter(|unit| unit.amount > 0).map(|unit| unit.to_string()));
+        if parts.is_empty() {
+            parts.push("0 business days".to_string());
+        }
+        f.write_str(&join_human(parts, &JoinOptions::default()))
+    }
+}
+
+impl Duration {
+    /// Expresses `self`, taken as an amount of working time, in business days of `calendar`,
+    /// ex) 16 working hours -> "2 business days." with 8 hour days.
+    pub fn to_business(&self, calendar: &BusinessCalendar) -> BusinessDuration {
+        let secs = self.total_seconds();
+        let day_secs = calendar.hours_per_day * 60 * 60;
+        BusinessDuration {
+            days: secs / day_secs,
+            remainder: Duration::new(secs % day_secs),
+        }
+    }
+
+    /// The number of ISO weeks `self` touches from the start of `start`, ex) 2 for a week from a
+    /// Friday. Any part of a day counts the day, and zero spans the week of `start` alone.
+    pub fn weeks_spanned<D: Into<Date>>(&self, start: D) -> usize {
+        let start = start.into();
+        let days = self.total_seconds().div_ceil(86_400).max(1) as i64;
+        let last = Date::from_days_since_epoch(start.days_since_epoch() + days - 1);
+        let monday = |date: Date| date.days_since_epoch() - date.weekday_index();
+        ((monday(last) - monday(start)) / 7 + 1) as usize
+    }
+
+    /// Counts the working time of `calendar` between `start` and `end`, taking days in UTC.
+    /// Returns a zero duration if `end` is not after `start`.
+    pub fn business_between(
+        start: SystemTime,
+        end: SystemTime,
+        calendar: &BusinessCalendar,
+    ) -> Self {
+        let (start, end) = (unix_secs(start), unix_secs(end));
+        let day_secs: i64 = 60 * 60 * 24;
+        let work_secs = (calendar.hours_per_day * 60 * 60) as i64;
+
+        let mut total: i64 = 0;
+        let mut day = start.div_euclid(day_secs) - 1;
+        while day * day_secs < end {
+            if calendar.is_working_day(Date::from_days_since_epoch(day)) {
+                let work_start = day * day_secs + calendar.day_start as i64;
+                let work_end = work_start + work_secs;
+                total += (work_end.min(end) - work_start.max(start)).max(0);
+            }
+            day += 1;
+        }
+        Duration::new(total as usize)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::{Duration as StdDuration, UNIX_EPOCH};
+
+    use business::{BusinessCalendar, Date, Region, Weekday};
+    use duration::Duration;
+    use error::Error;
+
+    #[test]
+    fn test_date_days_since_epoch() {
+        assert!(Date::new(1970, 1, 1).days_since_epoch() == 0);
+        assert!(Date::new(2024, 2, 29).days_since_epoch() == 19_782);
+        assert!(Date::from_days_since_epoch(19_782) == Date::new(2024, 2, 29));
+        assert!(Date::from_days_since_epoch(-1) == Date::new(1969, 12, 31));
+        assert!(Date::new(2024, 2, 29).weekday() == Weekday::Thursday);
+    }
+
+    #[test]
+    fn test_iso_week() {
+        assert!(Date::new(2022, 1, 1).iso_week() == (2021, 52));
+        assert!(Date::new(2021, 1, 3).iso_week() == (2020, 53));
+        assert!(Date::new(2024, 12, 30).iso_week() == (2025, 1));
+        assert!(Date::new(2024, 1, 1).iso_week() == (2024, 1));
+        assert!(Date::new(2024, 6, 27).iso_week() == (2024, 26));
+    }
+
+    #[test]
+    fn test_weeks_spanned() {
+        let friday = Date::new(2024, 3, 1);
+        assert!(Duration::new(7 * 86_400).weeks_spanned(friday) == 2);
+        assert!(Duration::new(3 * 86_400).weeks_spanned(friday) == 1);
+        assert!(Duration::new(3 * 86_400 + 1).weeks_spanned(friday) == 2);
+        assert!(Duration::new(0).weeks_spanned(friday) == 1);
+        assert!(Duration::new(7 * 86_400).weeks_spanned(Date::new(2024, 3, 4)) == 1);
+        assert!(Duration::new(31 * 86_400).weeks_spanned(Date::new(2024, 12, 1)) == 6);
+    }
+
+    #[test]
+    fn test_is_working_day() {
+        let calendar = BusinessCalendar::default().holiday(Date::new(2024, 12, 25));
+        assert!(calendar.is_working_day(Date::new(2024, 12, 24)));
+        assert!(!calendar.is_working_day(Date::new(2024, 12, 25)));
+        assert!(!calendar.is_working_day(Date::new(2024, 12, 28)));
+
+        let calendar = calendar.weekend(&[Weekday::Friday, Weekday::Saturday]);
+        assert!(calendar.is_working_day(Date::new(2024, 12, 29)));
+        assert!(!calendar.is_working_day(Date::new(2024, 12, 27)));
+    }
+
+    #[test]
+    fn test_business_calendar_new() {
+        assert!(BusinessCalendar::new(8) == Ok(BusinessCalendar::default()));
+        assert!(BusinessCalendar::new(1).unwrap().hours_per_day() == 1);
+        assert!(BusinessCalendar::new(24).unwrap().hours_per_day() == 24);
+        assert!(BusinessCalendar::new(0) == Err(Error::InvalidFormat));
+        assert!(BusinessCalendar::new(25) == Err(Error::InvalidFormat));
+        assert!(BusinessCalendar::new(usize::MAX) == Err(Error::InvalidFormat));
+    }
+
+    #[test]
+    fn test_to_business() {
+        let calendar = BusinessCalendar::default();
+        let business = Duration::new(16 * 3600).to_business(&calendar);
+        assert!(business.days == 2);
+        assert!(format!("{}", business) == "2 business days.");
+
+        let business = Duration::new(11 * 3600 + 1800).to_business(&calendar);
+        assert!(format!("{}", business) == "1 business day, 3 hours and 30 minutes.");
+
+        let business = Duration::new(16 * 3600).to_business(&BusinessCalendar::new(6).unwrap());
+        assert!(format!("{}", business) == "2 business days and 4 hours.");
+
+        let business = Duration::ZERO.to_business(&calendar);
+        assert!(format!("{}", business) == "0 business days.");
+    }
+
+    #[test]
+    fn test_presets() {
+        let calendar = BusinessCalendar::preset(Region::MiddleEast);
+        assert!(!calendar.is_working_day(Date::new(2024, 12, 27)));
+        assert!(calendar.is_working_day(Date::new(2024, 12, 29)));
+        assert!(calendar.week_start(Date::new(2024, 12, 25)) == Date::new(2024, 12, 22));
+
+        let calendar = BusinessCalendar::preset(Region::Europe);
+        assert!(calendar.week_start(Date::new(2024, 12, 25)) == Date::new(2024, 12, 23));
+        assert!(calendar.week_start(Date::new(2024, 12, 23)) == Date::new(2024, 12, 23));
+    }
+
+    #[test]
+    fn test_business_between() {
+        let holidays = vec![Date::new(2024, 12, 25), Date::new(2024, 12, 26)];
+        let calendar = BusinessCalendar::default().holidays(holidays);
+        let at = |date: Date, hour: u64| {
+            UNIX_EPOCH + StdDuration::from_secs(date.days_since_epoch() as u64 * 86_400 + hour * 3600)
+        };
+
+        // Tuesday 12:00 to Tuesday 15:00.
+        let d = Duration::business_between(
+            at(Date::new(2024, 12, 24), 12),
+            at(Date::new(2024, 12, 24), 15),
+            &calendar,
+        );
+        assert!(d == Duration::new(3 * 3600));
+
+        // Monday 16:00 to the following Monday 10:00, skipping two holidays and a weekend.
+        let d = Duration::business_between(
+            at(Date::new(2024, 12, 23), 16),
+            at(Date::new(2024, 12, 30), 10),
+            &calendar,
+        );
+        assert!(d == Duration::new((1 + 8 + 8 + 1) * 3600));
+
+        let d = Duration::business_between(
+            at(Date::new(2024, 12, 30), 10),
+            at(Date::new(2024, 12, 23), 16),
+            &calendar,
+        );
+        assert!(d == Duration::new(0));
+    }
+}
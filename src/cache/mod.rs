@@ -0,0 +1,94 @@
+//! Memoized formatting, for interfaces which render the same durations on every frame.
+
+#[cfg(not(feature = "sync"))]
+use std::cell::OnceCell as Cell;
+use std::fmt;
+#[cfg(feature = "sync")]
+use std::sync::OnceLock as Cell;
+
+use duration::{Duration, FormatOptions, Style};
+
+/// A [`Duration`](../duration/struct.Duration.html) along with its
+/// [`FormatOptions`](../duration/struct.FormatOptions.html), formatted on first use only. With
+/// the `sync` feature the cache is thread-safe, so that a `CachedDisplay` can be shared between
+/// threads, otherwise it avoids the cost of synchronization.
+#[derive(Clone)]
+pub struct CachedDisplay {
+    duration: Duration,
+    options: FormatOptions,
+    formatted: Cell<String>,
+}
+
+impl CachedDisplay {
+    pub fn new(duration: Duration, options: FormatOptions) -> Self {
+        CachedDisplay {
+            duration,
+            options,
+            formatted: Cell::new(),
+        }
+    }
+
+    pub fn duration(&self) -> Duration {
+        self.duration
+    }
+
+    pub fn options(&self) -> &FormatOptions {
+        &self.options
+    }
+
+    /// The formatted duration, computed by the first call only.
+    pub fn as_str(&self) -> &str {
+        self.formatted.get_or_init(|| self.duration.format_with(&self.options))
+    }
+}
+
+impl fmt::Display for CachedDisplay {
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        f.pad(self.as_str())
+    }
+}
+
+impl Duration {
+    /// Wraps `self` in a [`CachedDisplay`](../cache/struct.CachedDisplay.html) of `style`.
+    pub fn cached(&self, style: Style) -> CachedDisplay {
+        self.cached_with(FormatOptions {
+            style,
+            ..FormatOptions::default()
+        })
+    }
+
+    /// Like [`cached`](struct.Duration.html#method.cached), formatting according to `options`.
+    pub fn cached_with(&self, options: FormatOptions) -> CachedDisplay {
+        CachedDisplay::new(*self, options)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use duration::{Duration, FormatOptions, Style};
+
+    #[test]
+    fn test_cached_display() {
+        let cached = Duration::new(7199).cached(Style::Compact);
+        assert!(cached.as_str() == "1h 59m 59s");
+        assert!(cached.as_str().as_ptr() == cached.as_str().as_ptr());
+        assert!(format!("{:>12}", cached) == "  1h 59m 59s");
+        assert!(cached.clone().to_string() == "1h 59m 59s");
+
+        let two_units = FormatOptions { style: Style::Compact, max_units: Some(2), ..FormatOptions::default() };
+        let cached = Duration::new(7199).cached_with(two_units.clone());
+        assert!(cached.as_str() == "1h 59m" && cached.options() == &two_units);
+    }
+
+    #[cfg(feature = "sync")]
+    #[test]
+    fn test_cached_display_sync() {
+        use std::sync::Arc;
+        use std::thread;
+
+        let cached = Arc::new(Duration::new(60).cached(Style::Prose));
+        let shared = Arc::clone(&cached);
+        let rendered = thread::spawn(move || shared.to_string()).join().unwrap();
+        assert!(rendered == "1 minute." && cached.as_str() == "1 minute.");
+    }
+}
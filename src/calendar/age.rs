@@ -0,0 +1,86 @@
+use std::time::SystemTime;
+
+use business::Date;
+use calendar::unix_secs;
+use duration::Duration;
+
+fn is_leap_year(year: i32) -> bool {
+    (year % 4 == 0 && year % 100 != 0) || year % 400 == 0
+}
+
+/// The birthday of someone born on `birth` in `year`. Those born on February 29th celebrate on
+/// February 28th in common years, as [`add_to`](../duration/struct.Duration.html#method.add_to)
+/// adds years.
+fn birthday(birth: Date, year: i32) -> Date {
+    if birth.month == 2 && birth.day == 29 && !is_leap_year(year) {
+        Date::new(year, 2, 28)
+    } else {
+        Date::new(year, birth.month, birth.day)
+    }
+}
+
+impl Duration {
+    /// The age on `on` of someone born on `birth`, counted by the calendar: whole years since
+    /// birth, then the days since the last birthday, ex) "34 years and 12 days.". Zero if `on`
+    /// precedes `birth`.
+    pub fn age_between<D: Into<Date>>(birth: D, on: D) -> Duration {
+        let (birth, on) = (birth.into(), on.into());
+        if on <= birth {
+            return Duration::new(0);
+        }
+        let mut years = on.year - birth.year;
+        if on < birthday(birth, on.year) {
+            years -= 1;
+        }
+        let last = birthday(birth, birth.year + years);
+
+        let mut age = Duration::new(0);
+        age.years.amount = years as usize;
+        age.days.amount = (on.days_since_epoch() - last.days_since_epoch()) as usize;
+        age
+    }
+
+    /// The age today, in UTC, of someone born on `birth`, see
+    /// [`age_between`](#method.age_between). Accepts dates of `chrono` and `time` behind the
+    /// features of the same name.
+    pub fn age_from<D: Into<Date>>(birth: D) -> Duration {
+        let today = Date::from_days_since_epoch(unix_secs(SystemTime::now()).div_euclid(86_400));
+        Duration::age_between(birth.into(), today)
+    }
+
+    /// Formats `self` as an age, keeping only its largest unit, ex) "34 years" or "1 day".
+    /// Smaller units are dropped rather than rounded, as ages are. Zero formats as "0 days".
+    pub fn format_age(&self) -> String {
+        match self.iter_units().next() {
+            Some(unit) => unit.to_string(),
+            None => "0 days".to_string(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use business::Date;
+    use duration::Duration;
+
+    #[test]
+    fn test_age_between() {
+        let age = Duration::age_between(Date::new(1990, 6, 15), Date::new(2024, 6, 27));
+        assert!(age.years.amount == 34 && age.days.amount == 12);
+        assert!(age.format_age() == "34 years");
+
+        let age = Duration::age_between(Date::new(1990, 6, 15), Date::new(2024, 6, 14));
+        assert!(age.years.amount == 33 && age.days.amount == 365);
+        assert!(Duration::age_between(Date::new(2024, 6, 15), Date::new(2024, 6, 16)).format_age() == "1 day");
+        assert!(Duration::age_between(Date::new(2024, 6, 15), Date::new(2000, 1, 1)).format_age() == "0 days");
+    }
+
+    #[test]
+    fn test_age_leap_day_birthday() {
+        let leapling = Date::new(2000, 2, 29);
+        assert!(Duration::age_between(leapling, Date::new(2023, 2, 27)).years.amount == 22);
+        assert!(Duration::age_between(leapling, Date::new(2023, 2, 28)).years.amount == 23);
+        let age = Duration::age_between(leapling, Date::new(2024, 2, 29));
+        assert!(age.years.amount == 24 && age.days.amount == 0);
+    }
+}
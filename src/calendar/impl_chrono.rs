@@ -0,0 +1,102 @@
+use std::convert::TryFrom;
+
+use chrono::{DateTime, Datelike, Months, NaiveDate, NaiveDateTime, TimeDelta, TimeZone};
+
+use business;
+use calendar::{CalendarAnchor, UnixTimestamp};
+
+fn months(months: usize) -> Option<Months> {
+    u32::try_from(months).ok().map(Months::new)
+}
+
+fn delta(secs: usize) -> Option<TimeDelta> {
+    TimeDelta::try_seconds(i64::try_from(secs).ok()?)
+}
+
+impl<Tz: TimeZone> CalendarAnchor for DateTime<Tz> {
+    fn add_months(self, months: usize) -> Option<Self> {
+        self.checked_add_months(self::months(months)?)
+    }
+
+    fn add_seconds(self, secs: usize) -> Option<Self> {
+        self.checked_add_signed(delta(secs)?)
+    }
+}
+
+impl CalendarAnchor for NaiveDateTime {
+    fn add_months(self, months: usize) -> Option<Self> {
+        self.checked_add_months(self::months(months)?)
+    }
+
+    fn add_seconds(self, secs: usize) -> Option<Self> {
+        self.checked_add_signed(delta(secs)?)
+    }
+}
+
+impl CalendarAnchor for NaiveDate {
+    fn add_months(self, months: usize) -> Option<Self> {
+        self.checked_add_months(self::months(months)?)
+    }
+
+    /// Only whole days are added to a date.
+    fn add_seconds(self, secs: usize) -> Option<Self> {
+        self.checked_add_signed(delta(secs - secs % (60 * 60 * 24))?)
+    }
+}
+
+impl<Tz: TimeZone> UnixTimestamp for DateTime<Tz> {
+    fn unix_timestamp(&self) -> i64 {
+        self.timestamp()
+    }
+}
+
+impl From<NaiveDate> for business::Date {
+    fn from(date: NaiveDate) -> Self {
+        business::Date::new(date.year(), date.month(), date.day())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{NaiveDate, Utc, TimeZone};
+
+    use duration::Duration;
+
+    #[test]
+    fn test_add_to_chrono() {
+        let anchor = NaiveDate::from_ymd_opt(2024, 2, 29).unwrap();
+        let one_year = Duration::new(365 * 86_400);
+        assert!(one_year.add_to(anchor) == NaiveDate::from_ymd_opt(2025, 2, 28));
+
+        let anchor = NaiveDate::from_ymd_opt(2023, 3, 1).unwrap();
+        assert!(one_year.add_to(anchor) == NaiveDate::from_ymd_opt(2024, 3, 1));
+
+        let anchor = Utc.with_ymd_and_hms(2023, 6, 15, 12, 0, 0).unwrap();
+        let d = Duration::new(2 * 365 * 86_400 + 86_400 + 3600);
+        assert!(d.add_to(anchor) == Some(Utc.with_ymd_and_hms(2025, 6, 16, 13, 0, 0).unwrap()));
+    }
+
+    #[test]
+    fn test_add_months_to_chrono() {
+        let anchor = NaiveDate::from_ymd_opt(2024, 3, 15).unwrap();
+        assert!(Duration::ZERO.add_months_to(1, anchor) == NaiveDate::from_ymd_opt(2024, 4, 15));
+
+        let anchor = NaiveDate::from_ymd_opt(2024, 1, 31).unwrap();
+        assert!(Duration::ZERO.add_months_to(1, anchor) == NaiveDate::from_ymd_opt(2024, 2, 29));
+        let anchor = NaiveDate::from_ymd_opt(2023, 1, 31).unwrap();
+        assert!(Duration::ZERO.add_months_to(1, anchor) == NaiveDate::from_ymd_opt(2023, 2, 28));
+
+        let anchor = NaiveDate::from_ymd_opt(2023, 11, 30).unwrap();
+        let d = Duration::new(365 * 86_400 + 86_400);
+        assert!(d.add_months_to(3, anchor) == NaiveDate::from_ymd_opt(2025, 3, 1));
+        assert!(Duration::ZERO.add_months_to(usize::MAX, anchor).is_none());
+    }
+
+    #[test]
+    fn test_age_chrono() {
+        let birth = NaiveDate::from_ymd_opt(1990, 6, 15).unwrap();
+        let on = NaiveDate::from_ymd_opt(2024, 6, 27).unwrap();
+        assert!(Duration::age_between(birth, on).format_age() == "34 years");
+        assert!(Duration::age_from(birth).years.amount >= 34);
+    }
+}
@@ -0,0 +1,103 @@
+use std::convert::TryFrom;
+
+use time::{Date, Month, OffsetDateTime, PrimitiveDateTime};
+
+use business;
+use calendar::{CalendarAnchor, UnixTimestamp};
+
+fn add_months_to_date(date: Date, months: usize) -> Option<Date> {
+    let month = i64::from(date.year()) * 12 + i64::from(u8::from(date.month()) - 1);
+    let month = month.checked_add(i64::try_from(months).ok()?)?;
+    let year = i32::try_from(month.div_euclid(12)).ok()?;
+    let month = Month::try_from(month.rem_euclid(12) as u8 + 1).ok()?;
+    Date::from_calendar_date(year, month, date.day().min(month.length(year))).ok()
+}
+
+fn delta(secs: usize) -> Option<time::Duration> {
+    Some(time::Duration::seconds(i64::try_from(secs).ok()?))
+}
+
+impl CalendarAnchor for OffsetDateTime {
+    fn add_months(self, months: usize) -> Option<Self> {
+        Some(self.replace_date(add_months_to_date(self.date(), months)?))
+    }
+
+    fn add_seconds(self, secs: usize) -> Option<Self> {
+        self.checked_add(delta(secs)?)
+    }
+}
+
+impl CalendarAnchor for PrimitiveDateTime {
+    fn add_months(self, months: usize) -> Option<Self> {
+        Some(self.replace_date(add_months_to_date(self.date(), months)?))
+    }
+
+    fn add_seconds(self, secs: usize) -> Option<Self> {
+        self.checked_add(delta(secs)?)
+    }
+}
+
+impl CalendarAnchor for Date {
+    fn add_months(self, months: usize) -> Option<Self> {
+        add_months_to_date(self, months)
+    }
+
+    /// Only whole days are added to a date.
+    fn add_seconds(self, secs: usize) -> Option<Self> {
+        self.checked_add(delta(secs - secs % (60 * 60 * 24))?)
+    }
+}
+
+impl UnixTimestamp for OffsetDateTime {
+    fn unix_timestamp(&self) -> i64 {
+        OffsetDateTime::unix_timestamp(*self)
+    }
+}
+
+impl From<Date> for business::Date {
+    fn from(date: Date) -> Self {
+        business::Date::new(date.year(), u8::from(date.month()) as u32, u32::from(date.day()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use time::{Date, Month};
+
+    use duration::Duration;
+
+    #[test]
+    fn test_add_to_time() {
+        let anchor = Date::from_calendar_date(2024, Month::February, 29).unwrap();
+        let one_year = Duration::new(365 * 86_400);
+        assert!(one_year.add_to(anchor) == Date::from_calendar_date(2025, Month::February, 28).ok());
+
+        let anchor = Date::from_calendar_date(2023, Month::March, 1).unwrap();
+        let d = Duration::new(365 * 86_400 + 86_400 + 3600);
+        assert!(d.add_to(anchor) == Date::from_calendar_date(2024, Month::March, 2).ok());
+    }
+
+    #[test]
+    fn test_add_months_to_time() {
+        let date = |year, month, day| Date::from_calendar_date(year, month, day).unwrap();
+        let anchor = date(2024, Month::March, 15);
+        assert!(Duration::ZERO.add_months_to(1, anchor) == Some(date(2024, Month::April, 15)));
+
+        let anchor = date(2024, Month::January, 31);
+        assert!(Duration::ZERO.add_months_to(1, anchor) == Some(date(2024, Month::February, 29)));
+        let anchor = date(2023, Month::January, 31);
+        assert!(Duration::ZERO.add_months_to(1, anchor) == Some(date(2023, Month::February, 28)));
+
+        let anchor = date(2023, Month::November, 30);
+        let d = Duration::new(365 * 86_400 + 86_400);
+        assert!(d.add_months_to(3, anchor) == Some(date(2025, Month::March, 1)));
+        assert!(Duration::ZERO.add_months_to(usize::MAX, anchor).is_none());
+    }
+
+    #[test]
+    fn test_age_time() {
+        let birth = Date::from_calendar_date(2000, Month::February, 29).unwrap();
+        let on = Date::from_calendar_date(2023, Month::February, 28).unwrap();
+        assert!(Duration::age_between(birth, on).format_age() == "23 years");
+    }
+}
@@ -0,0 +1,52 @@
+use business::Date;
+use duration::Duration;
+use error::Error;
+
+impl Duration {
+    /// The exact length of `month` (1 to 12) in `year`, ex) 29 days for February 2024. Other
+    /// months are an [`Error::InvalidFormat`](../error/enum.Error.html).
+    pub fn of_month(year: i32, month: u32) -> Result<Duration, Error> {
+        if !(1..=12).contains(&month) {
+            return Err(Error::InvalidFormat);
+        }
+        let next = if month == 12 {
+            Date::new(year + 1, 1, 1)
+        } else {
+            Date::new(year, month + 1, 1)
+        };
+        let days = next.days_since_epoch() - Date::new(year, month, 1).days_since_epoch();
+        Ok(Duration::new(days as usize * 86_400))
+    }
+
+    /// The exact length of `year`, 366 days in leap years and 365 days otherwise.
+    pub fn of_year(year: i32) -> Duration {
+        let (start, end) = (Date::new(year, 1, 1), Date::new(year + 1, 1, 1));
+        let days = end.days_since_epoch() - start.days_since_epoch();
+        Duration::new(days as usize * 86_400)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use duration::Duration;
+    use error::Error;
+
+    #[test]
+    fn test_of_month() {
+        assert!(Duration::of_month(2024, 2) == Ok(Duration::new(29 * 86_400)));
+        assert!(Duration::of_month(2023, 2) == Ok(Duration::new(28 * 86_400)));
+        assert!(Duration::of_month(1900, 2) == Ok(Duration::new(28 * 86_400)));
+        assert!(Duration::of_month(2000, 2) == Ok(Duration::new(29 * 86_400)));
+        assert!(Duration::of_month(2024, 4) == Ok(Duration::new(30 * 86_400)));
+        assert!(Duration::of_month(2024, 12) == Ok(Duration::new(31 * 86_400)));
+        assert!(Duration::of_month(2024, 0) == Err(Error::InvalidFormat));
+        assert!(Duration::of_month(2024, 13) == Err(Error::InvalidFormat));
+    }
+
+    #[test]
+    fn test_of_year() {
+        assert!(Duration::of_year(2024) == Duration::new(366 * 86_400));
+        assert!(Duration::of_year(2023).to_string() == "1 year.");
+        assert!(Duration::of_year(1900) == Duration::new(365 * 86_400));
+    }
+}
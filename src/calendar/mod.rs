@@ -0,0 +1,77 @@
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use duration::Duration;
+
+mod age;
+#[cfg(feature = "chrono")]
+mod impl_chrono;
+#[cfg(feature = "time")]
+mod impl_time;
+mod length;
+
+/// Seconds since the Unix epoch, negative before it.
+pub(crate) fn unix_secs(t: SystemTime) -> i64 {
+    match t.duration_since(UNIX_EPOCH) {
+        Ok(d) => d.as_secs() as i64,
+        Err(e) => -(e.duration().as_secs() as i64),
+    }
+}
+
+/// A point in time which can be read as seconds since the Unix epoch. Implemented for `i64`
+/// epoch seconds and `SystemTime`, for `chrono::DateTime` behind the `chrono` feature and for
+/// `time::OffsetDateTime` behind the `time` feature.
+pub trait UnixTimestamp {
+    fn unix_timestamp(&self) -> i64;
+}
+
+impl UnixTimestamp for i64 {
+    fn unix_timestamp(&self) -> i64 {
+        *self
+    }
+}
+
+impl UnixTimestamp for SystemTime {
+    fn unix_timestamp(&self) -> i64 {
+        unix_secs(*self)
+    }
+}
+
+/// A date or timestamp which a [`Duration`](../duration/struct.Duration.html) can be added to
+/// with calendar arithmetic. Implemented for `chrono` types behind the `chrono` feature and for
+/// `time` types behind the `time` feature.
+pub trait CalendarAnchor: Sized {
+    /// Adds `months` calendar months, keeping the day of month, or the last day of the month
+    /// when it is shorter, ex) January 31st becomes February 29th in 2024. Returns `None` if the
+    /// result is out of range.
+    fn add_months(self, months: usize) -> Option<Self>;
+
+    /// Adds `years` calendar years, keeping the month and day of month. February 29th becomes
+    /// February 28th in common years. Returns `None` if the result is out of range.
+    fn add_years(self, years: usize) -> Option<Self> {
+        self.add_months(years.checked_mul(12)?)
+    }
+
+    /// Adds an exact amount of seconds. Returns `None` if the result is out of range.
+    fn add_seconds(self, secs: usize) -> Option<Self>;
+}
+
+impl Duration {
+    /// Adds `self` to `anchor`, counting years by the calendar instead of as 365 days, so that
+    /// "in 1 year" lands on the same day of the same month next year. The smaller units are
+    /// added as exact amounts of seconds afterwards. Returns `None` if the result is out of range.
+    pub fn add_to<T: CalendarAnchor>(&self, anchor: T) -> Option<T> {
+        self.add_months_to(0, anchor)
+    }
+
+    /// Like [`add_to`](struct.Duration.html#method.add_to), adding `months` calendar months
+    /// along with the years, as a `Duration` has no unit for months, ex) "in 1 month" from March
+    /// 15th is `Duration::ZERO.add_months_to(1, anchor)`, April 15th.
+    pub fn add_months_to<T: CalendarAnchor>(&self, months: usize, anchor: T) -> Option<T> {
+        let months = self.years.amount.checked_mul(12)?.checked_add(months)?;
+        let mut rest = *self;
+        rest.years.amount = 0;
+        anchor
+            .add_months(months)?
+            .add_seconds(rest.try_into_std().ok()?.as_secs() as usize)
+    }
+}
@@ -0,0 +1,119 @@
+//! Marker wrappers telling monotonic elapsed time apart from calendar spans, so that an API
+//! taking one cannot be handed the other by mistake. Each wrapper only adds to its own kind.
+
+use std::fmt;
+use std::ops::{Add, Deref};
+use std::time::{Instant, SystemTime};
+
+use duration::Duration;
+
+/// Elapsed time measured by a monotonic clock, ex) between two `Instant`s.
+#[derive(PartialEq, Clone, Copy)]
+pub struct Monotonic<D = Duration>(pub D);
+
+/// A span of calendar or wall-clock time, ex) between two `SystemTime`s. Wall clocks may jump,
+/// so such spans are unsuitable for measuring elapsed time.
+#[derive(PartialEq, Clone, Copy)]
+pub struct WallClock<D = Duration>(pub D);
+
+impl Monotonic<Duration> {
+    /// The time elapsed from `start` to `end`, or zero if `end` is earlier.
+    pub fn between(start: Instant, end: Instant) -> Self {
+        let elapsed = end.saturating_duration_since(start);
+        Monotonic(Duration::try_from_std(elapsed).unwrap_or_else(|_| Duration::new(usize::MAX)))
+    }
+
+    /// The time elapsed since `start`.
+    pub fn since(start: Instant) -> Self {
+        Monotonic::between(start, Instant::now())
+    }
+}
+
+impl WallClock<Duration> {
+    /// The wall-clock delta from `start` to `end`, or zero if `end` is earlier.
+    pub fn between(start: SystemTime, end: SystemTime) -> Self {
+        WallClock(Duration::between_wall_clock(start, end, None))
+    }
+}
+
+impl<D> Monotonic<D> {
+    pub fn into_inner(self) -> D {
+        self.0
+    }
+}
+
+impl<D> WallClock<D> {
+    pub fn into_inner(self) -> D {
+        self.0
+    }
+}
+
+impl<D> Deref for Monotonic<D> {
+    type Target = D;
+
+    fn deref(&self) -> &D {
+        &self.0
+    }
+}
+
+impl<D> Deref for WallClock<D> {
+    type Target = D;
+
+    fn deref(&self) -> &D {
+        &self.0
+    }
+}
+
+impl<D: Add<Output = D>> Add for Monotonic<D> {
+    type Output = Monotonic<D>;
+
+    fn add(self, rhs: Monotonic<D>) -> Monotonic<D> {
+        Monotonic(self.0 + rhs.0)
+    }
+}
+
+impl<D: Add<Output = D>> Add for WallClock<D> {
+    type Output = WallClock<D>;
+
+    fn add(self, rhs: WallClock<D>) -> WallClock<D> {
+        WallClock(self.0 + rhs.0)
+    }
+}
+
+impl<D: fmt::Display> fmt::Display for Monotonic<D> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        self.0.fmt(f)
+    }
+}
+
+impl<D: fmt::Display> fmt::Display for WallClock<D> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        self.0.fmt(f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::{Duration as StdDuration, Instant, UNIX_EPOCH};
+
+    use clock::{Monotonic, WallClock};
+    use duration::Duration;
+
+    #[test]
+    fn test_monotonic_between() {
+        let start = Instant::now();
+        let end = start + StdDuration::from_secs(90);
+        assert!(Monotonic::between(start, end) == Monotonic(Duration::new(90)));
+        assert!(Monotonic::between(end, start) == Monotonic(Duration::new(0)));
+        assert!(format!("{}", Monotonic::between(start, end)) == "1 minute and 30 seconds.");
+    }
+
+    #[test]
+    fn test_wall_clock_add() {
+        let start = UNIX_EPOCH;
+        let span = WallClock::between(start, start + StdDuration::from_secs(60));
+        let total = span + WallClock(Duration::new(30));
+        assert!(total.into_inner() == Duration::new(90));
+        assert!(total.days.amount == 0);
+    }
+}
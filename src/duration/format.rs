@@ -0,0 +1,609 @@
+use std::collections::BTreeMap;
+use std::fmt;
+use std::str;
+
+use duration::words::spell;
+use duration::{Duration, TimeUnit, TimeUnitKind, KINDS};
+use error::Error;
+use join::{join_human, JoinOptions};
+
+/// Selects one of the formatting modes of a [`Duration`](struct.Duration.html).
+#[derive(PartialEq, Clone, Copy, Debug, Default)]
+pub enum Style {
+    /// The `Display` prose, ex) "1 hour, 59 minutes and 59 seconds.".
+    #[default]
+    Prose,
+    /// See [`format_compact`](struct.Duration.html#method.format_compact), ex) "1h 59m 59s".
+    Compact,
+    /// See [`format_approximate`](struct.Duration.html#method.format_approximate), ex)
+    /// "about 2 hours".
+    Approximate,
+    /// Prose for screen readers: unit names in full and no punctuation they may voice, ex) "1
+    /// hour 59 minutes and 59 seconds". Zero formats as "0 seconds".
+    Spoken,
+}
+
+/// How [`format_with`](struct.Duration.html#method.format_with) formats a
+/// [`Duration`](struct.Duration.html).
+#[derive(PartialEq, Clone, Debug, Default)]
+pub struct FormatOptions {
+    pub style: Style,
+    /// Keeps only this many of the largest non-zero units, dropping the rest, ex) "1h 59m" for
+    /// 2 units. Does not apply to `Style::Approximate`, which shows a single unit anyway.
+    pub max_units: Option<usize>,
+    /// Spells amounts out in words for `Style::Spoken`, ex) "one hour fifty nine minutes and
+    /// fifty nine seconds". Ignored by the other styles.
+    pub spell_out: bool,
+    /// Renames units in every style, ex) days as "sols". Compact labels follow the amount
+    /// directly, ex) "2sols 1h", the other styles put a space between.
+    pub labels: BTreeMap<TimeUnitKind, UnitLabel>,
+}
+
+/// A caller-supplied name for a unit in [`FormatOptions`](struct.FormatOptions.html).
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub struct UnitLabel {
+    pub singular: String,
+    pub plural: String,
+}
+
+impl UnitLabel {
+    pub fn new(singular: &str, plural: &str) -> Self {
+        UnitLabel {
+            singular: singular.to_string(),
+            plural: plural.to_string(),
+        }
+    }
+
+    /// The same label for any amount, ex) "h".
+    pub fn fixed(label: &str) -> Self {
+        UnitLabel::new(label, label)
+    }
+
+    fn for_amount(&self, amount: usize) -> &str {
+        if amount == 1 {
+            &self.singular
+        } else {
+            &self.plural
+        }
+    }
+}
+
+impl FormatOptions {
+    /// Labels `kind` as `label`, ex) days as `UnitLabel::new("sol", "sols")`.
+    pub fn label(mut self, kind: TimeUnitKind, label: UnitLabel) -> Self {
+        self.labels.insert(kind, label);
+        self
+    }
+
+    /// The prose name of `unit`, its label if one was given.
+    fn name(&self, unit: &TimeUnit) -> &str {
+        match self.labels.get(&unit.kind) {
+            Some(label) => label.for_amount(unit.amount),
+            None if unit.amount == 1 => unit.kind.name(),
+            None => unit.kind.plural_name(),
+        }
+    }
+
+    /// The compact symbol of `unit`, its label if one was given.
+    fn symbol(&self, unit: &TimeUnit) -> &str {
+        match self.labels.get(&unit.kind) {
+            Some(label) => label.for_amount(unit.amount),
+            None => unit.kind.symbol(),
+        }
+    }
+}
+
+/// The length of the longest compact form, "584942417355y 364d 23h 59m 59s".
+const COMPACT_CAPACITY: usize = 30;
+
+/// Writes the decimal digits of `n` to the end of `digits`, which fits any `usize`, and returns
+/// them.
+fn decimal(mut n: usize, digits: &mut [u8; 20]) -> &str {
+    let mut start = digits.len();
+    loop {
+        start -= 1;
+        digits[start] = b'0' + (n % 10) as u8;
+        n /= 10;
+        if n == 0 {
+            break;
+        }
+    }
+    // Only ascii digits were written.
+    str::from_utf8(&digits[start..]).unwrap_or_default()
+}
+
+/// The version of the grammar of [`format_stable`](struct.Duration.html#method.format_stable).
+pub const STABLE_GRAMMAR_VERSION: u32 = 1;
+
+impl Duration {
+    /// Formats `Self` according to `style`.
+    pub fn format_style(&self, style: Style) -> String {
+        match style {
+            Style::Prose => self.to_string(),
+            Style::Compact => self.format_compact(),
+            Style::Approximate => self.format_approximate(),
+            Style::Spoken => self.format_with(&FormatOptions {
+                style,
+                ..FormatOptions::default()
+            }),
+        }
+    }
+
+    /// Formats `Self` according to `options`.
+    pub fn format_with(&self, options: &FormatOptions) -> String {
+        let mut s = String::new();
+        let _ = self.write_with(&mut s, options);
+        s
+    }
+
+    /// Like [`format_with`](struct.Duration.html#method.format_with), writing to `w` instead,
+    /// which lets batches of durations share one buffer.
+    pub fn write_with<W: fmt::Write>(&self, w: &mut W, options: &FormatOptions) -> fmt::Result {
+        let max_units = options.max_units.unwrap_or(usize::MAX);
+        match options.style {
+            Style::Prose if self.is_zero() => {
+                let zero = TimeUnit::new(TimeUnitKind::Seconds, 0);
+                write!(w, "0 {}.", options.name(&zero))
+            }
+            Style::Prose if options.labels.is_empty() => {
+                let units = self.iter_units().take(max_units).collect::<Vec<&TimeUnit>>();
+                w.write_str(&join_human(units, &JoinOptions::default()))
+            }
+            Style::Prose => {
+                let units = self.iter_units().take(max_units);
+                let units = units.map(|unit| format!("{} {}", unit.amount, options.name(unit)));
+                w.write_str(&join_human(units, &JoinOptions::default()))
+            }
+            Style::Compact => self.write_compact(w, options, max_units),
+            Style::Approximate => match self.approximate_unit() {
+                Some(unit) => write!(w, "about {} {}", unit.amount, options.name(&unit)),
+                None => w.write_str("less than a second"),
+            },
+            Style::Spoken => self.write_spoken(w, options, max_units),
+        }
+    }
+
+    /// Writes the `max_units` largest units joined by spaces and a final "and", without commas
+    /// or a terminating period.
+    fn write_spoken<W: fmt::Write>(
+        &self,
+        w: &mut W,
+        options: &FormatOptions,
+        max_units: usize,
+    ) -> fmt::Result {
+        let amount = |n: usize| if options.spell_out { spell(n) } else { n.to_string() };
+        if self.is_zero() {
+            let zero = TimeUnit::new(TimeUnitKind::Seconds, 0);
+            return write!(w, "{} {}", amount(0), options.name(&zero));
+        }
+        let units = self.iter_units().take(max_units).collect::<Vec<&TimeUnit>>();
+        for (i, unit) in units.iter().enumerate() {
+            if i > 0 {
+                w.write_str(if i + 1 == units.len() { " and " } else { " " })?;
+            }
+            write!(w, "{} {}", amount(unit.amount), options.name(unit))?;
+        }
+        Ok(())
+    }
+
+    /// Formats the units of `Self` as amounts with single letter suffixes separated by spaces,
+    /// ex) "1y 44d 1h 59m 59s". Zero formats as "0s".
+    pub fn format_compact(&self) -> String {
+        let mut s = String::with_capacity(COMPACT_CAPACITY);
+        let _ = self.write_compact(&mut s, &FormatOptions::default(), usize::MAX);
+        s
+    }
+
+    /// Writes the `max_units` largest units of the compact form, bypassing `fmt` for the amounts.
+    fn write_compact<W: fmt::Write>(
+        &self,
+        w: &mut W,
+        options: &FormatOptions,
+        max_units: usize,
+    ) -> fmt::Result {
+        if self.is_zero() {
+            w.write_char('0')?;
+            return w.write_str(options.symbol(&TimeUnit::new(TimeUnitKind::Seconds, 0)));
+        }
+        let mut digits = [0u8; 20];
+        for (i, unit) in self.iter_units().take(max_units).enumerate() {
+            if i > 0 {
+                w.write_char(' ')?;
+            }
+            w.write_str(decimal(unit.amount, &mut digits))?;
+            w.write_str(options.symbol(unit))?;
+        }
+        Ok(())
+    }
+
+    /// Formats `Self` in a grammar which is guaranteed not to change within a
+    /// [`STABLE_GRAMMAR_VERSION`](constant.STABLE_GRAMMAR_VERSION.html), making it suitable for
+    /// hashing, signing or diffing. Version 1 is, in ABNF:
+    ///
+    /// ```text
+    /// stable = years "y" days "d" hours "h" minutes "m" seconds "s"
+    /// years = 1*DIGIT
+    /// ; days, hours, minutes and seconds likewise
+    /// ```
+    ///
+    /// Every unit is always present, amounts are in decimal without leading zeros and are
+    /// normalized from the total amount of seconds, with years of 365 days, ex) "1y44d1h59m59s" or
+    /// "0y0d0h0m0s". The output reads back through `FromStr`.
+    pub fn format_stable(&self) -> String {
+        let d = Duration::new(self.total_seconds());
+        format!(
+            "{}y{}d{}h{}m{}s",
+            d.years.amount, d.days.amount, d.hours.amount, d.minutes.amount, d.seconds.amount
+        )
+    }
+
+    /// Formats `Self` the way `uptime(1)` reports how long a system has been up: whole days in
+    /// words, then hours and minutes as `H:MM`, or only minutes while below one hour. Years are
+    /// counted as days and seconds are dropped.
+    /// * ex) 3 days, 2 hours and 14 minutes -> "up 3 days,  2:14"
+    /// * ex) 1 day and 14 minutes -> "up 1 day, 14 min"
+    /// * ex) 2 hours and 14 minutes -> "up  2:14"
+    pub fn format_uptime(&self) -> String {
+        let secs = self.total_seconds();
+        let days = secs / (60 * 60 * 24);
+        let hours = secs % (60 * 60 * 24) / (60 * 60);
+        let minutes = secs % (60 * 60) / 60;
+
+        let mut s = String::from("up ");
+        if days > 0 {
+            s.push_str(&format!("{} day{}, ", days, if days != 1 { "s" } else { "" }));
+        }
+        if hours > 0 {
+            s.push_str(&format!("{:2}:{:02}", hours, minutes));
+        } else {
+            s.push_str(&format!("{} min", minutes));
+        }
+        s
+    }
+
+    /// Formats `Self` as its largest unit only, rounded to the nearest whole amount, ex) 2 minutes
+    /// and 40 seconds -> "about 3 minutes". Rounding up into the next larger unit promotes to it,
+    /// ex) 59 minutes and 40 seconds -> "about 1 hour". Zero formats as "less than a second".
+    pub fn format_approximate(&self) -> String {
+        match self.approximate_unit() {
+            Some(unit) => format!("about {}", unit),
+            None => "less than a second".to_string(),
+        }
+    }
+
+    /// Picks a representation of `Self` which fits in `max_chars` characters, preferring
+    /// precision: prose, then compact, then compact rounded to fewer and fewer units and marked
+    /// with "~", ex) "1 hour and 59 minutes", then "1h 59m", then "~2h". If nothing fits, the
+    /// single unit form is cut short with an ellipsis, ex) "~…".
+    ///
+    /// With the `unicode-width` feature, characters are counted by their display width in a
+    /// terminal rather than one each.
+    pub fn format_fit(&self, max_chars: usize) -> String {
+        let fits = |s: &str| width(s) <= max_chars;
+        let units = self.iter_units().cloned().collect::<Vec<TimeUnit>>();
+        if units.is_empty() {
+            return truncate(if fits("0 seconds") { "0 seconds" } else { "0s" }, max_chars);
+        }
+
+        let prose = self.to_string();
+        let prose = prose.trim_end_matches('.');
+        if fits(prose) {
+            return prose.to_string();
+        }
+        let compact = self.format_compact();
+        if fits(&compact) {
+            return compact;
+        }
+
+        let secs = self.total_seconds();
+        let mut approximate = compact;
+        for kept in (1..units.len()).rev() {
+            let unit_secs = units[kept - 1].kind.as_seconds();
+            let round_up = secs % unit_secs >= unit_secs.div_ceil(2);
+            let rounded = (secs / unit_secs + if round_up { 1 } else { 0 }) * unit_secs;
+            approximate = format!("~{}", Duration::new(rounded).format_compact());
+            if fits(&approximate) {
+                break;
+            }
+        }
+        truncate(&approximate, max_chars)
+    }
+
+    /// Formats the `max_units` largest non-zero units of `Self` as prose and returns the dropped
+    /// units alongside, so that callers can hint at them, ex) 1 hour, 59 minutes and 59 seconds
+    /// limited to 2 units is "1 hour and 59 minutes." with 59 seconds remaining, to be shown as
+    /// "…and 59 seconds more". Units are dropped rather than rounded. Zero formats as "0
+    /// seconds.", otherwise with no unit left to show, the prose is empty.
+    pub fn format_limited(&self, max_units: usize) -> (String, Duration) {
+        if self.is_zero() {
+            return ("0 seconds.".to_string(), Duration::ZERO);
+        }
+        let units = self.iter_units().cloned().collect::<Vec<TimeUnit>>();
+        let (kept, dropped) = units.split_at(max_units.min(units.len()));
+
+        let mut remainder = Duration::ZERO;
+        for unit in dropped {
+            let slot = match unit.kind {
+                TimeUnitKind::Years => &mut remainder.years,
+                TimeUnitKind::Days => &mut remainder.days,
+                TimeUnitKind::Hours => &mut remainder.hours,
+                TimeUnitKind::Minutes => &mut remainder.minutes,
+                TimeUnitKind::Seconds => &mut remainder.seconds,
+            };
+            slot.amount = unit.amount;
+        }
+        (join_human(kept, &JoinOptions::default()), remainder)
+    }
+
+    /// The largest unit of `Self`, rounded to the nearest whole amount, promoting to the next
+    /// larger unit when rounding up reaches it. `None` for zero.
+    pub(crate) fn approximate_unit(&self) -> Option<TimeUnit> {
+        let secs = self.total_seconds();
+        let index = KINDS.iter().rposition(|kind| secs >= kind.as_seconds())?;
+
+        let rounded = |index: usize| {
+            let unit_secs = KINDS[index].as_seconds();
+            let round_up = secs % unit_secs >= unit_secs.div_ceil(2);
+            TimeUnit::new(KINDS[index], secs / unit_secs + if round_up { 1 } else { 0 })
+        };
+        let mut unit = rounded(index);
+        if let Some(larger) = KINDS.get(index + 1) {
+            if unit.amount * KINDS[index].as_seconds() >= larger.as_seconds() {
+                unit = rounded(index + 1);
+            }
+        }
+        Some(unit)
+    }
+
+    /// Formats `Self` as a float amount of seconds, the base unit Prometheus exporters expect,
+    /// ex) "7199.000".
+    pub fn as_prometheus(&self) -> String {
+        format!("{}.000", self.total_seconds())
+    }
+
+    /// Parses a float amount of seconds as rendered by
+    /// [`as_prometheus`](struct.Duration.html#method.as_prometheus), ex) "7199.000" or
+    /// "7.199e3". Sub-second precision is truncated.
+    pub fn from_prometheus(s: &str) -> Result<Self, Error> {
+        let secs: f64 = s.trim().parse().map_err(|_| Error::InvalidFormat)?;
+        if secs.is_nan() || secs < 0.0 {
+            return Err(Error::InvalidFormat);
+        }
+        if secs >= usize::MAX as f64 {
+            return Err(Error::Overflow);
+        }
+        Ok(Duration::new(secs as usize))
+    }
+}
+
+/// The display width of `s`.
+#[cfg(feature = "unicode-width")]
+fn width(s: &str) -> usize {
+    ::unicode_width::UnicodeWidthStr::width(s)
+}
+
+/// The amount of characters in `s`.
+#[cfg(not(feature = "unicode-width"))]
+fn width(s: &str) -> usize {
+    s.chars().count()
+}
+
+/// Cuts `s` short with an ellipsis if it is wider than `max_width`.
+fn truncate(s: &str, max_width: usize) -> String {
+    if width(s) <= max_width {
+        return s.to_string();
+    }
+    let mut truncated = String::new();
+    for c in s.chars() {
+        let mut next = truncated.clone();
+        next.push(c);
+        if width(&next) + width("…") > max_width {
+            break;
+        }
+        truncated = next;
+    }
+    if width("…") <= max_width {
+        truncated.push('…');
+    }
+    truncated
+}
+
+impl From<Duration> for String {
+    /// Formats in the compact [`Style`](enum.Style.html), which makes `Duration` usable with
+    /// `#[serde(into = "String")]` and reads back with `TryFrom<String>`.
+    fn from(d: Duration) -> String {
+        d.format_compact()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use duration::{Duration, FormatOptions, Style, TimeUnitKind, UnitLabel};
+    use error::Error;
+
+    #[test]
+    fn test_format_uptime() {
+        assert!(Duration::new(3 * 86_400 + 2 * 3600 + 14 * 60).format_uptime() == "up 3 days,  2:14");
+        assert!(Duration::new(86_400 + 14 * 60 + 59).format_uptime() == "up 1 day, 14 min");
+        assert!(Duration::new(12 * 3600 + 5 * 60).format_uptime() == "up 12:05");
+        assert!(Duration::new(59).format_uptime() == "up 0 min");
+        assert!(Duration::new(31_536_000).format_uptime() == "up 365 days, 0 min");
+    }
+
+    #[test]
+    fn test_format_compact() {
+        assert!(Duration::new(7199).format_compact() == "1h 59m 59s");
+        assert!(Duration::new(35_344_799).format_compact() == "1y 44d 1h 59m 59s");
+        assert!(Duration::new(3600).format_compact() == "1h");
+        assert!(Duration::new(0).format_compact() == "0s");
+    }
+
+    #[test]
+    fn test_format_compact_extremes() {
+        assert!(Duration::MAX.format_compact().len() <= super::COMPACT_CAPACITY);
+        assert!(Duration::new(10).format_compact() == "10s");
+        assert!(Duration::new(1_000_000 * 31_536_000).format_compact() == "1000000y");
+    }
+
+    #[test]
+    fn test_format_stable() {
+        assert!(Duration::new(35_344_799).format_stable() == "1y44d1h59m59s");
+        assert!(Duration::new(0).format_stable() == "0y0d0h0m0s");
+
+        let mut denormalized = Duration::new(0);
+        denormalized.minutes.amount = 75;
+        assert!(denormalized.format_stable() == "0y0d1h15m0s");
+        assert!("1y44d1h59m59s".parse::<Duration>() == Ok(Duration::new(35_344_799)));
+    }
+
+    #[test]
+    fn test_into_string() {
+        let s: String = Duration::new(7199).into();
+        assert!(s == "1h 59m 59s");
+    }
+
+    #[test]
+    fn test_format_style() {
+        let d = Duration::new(7199);
+        assert!(d.format_style(Style::Prose) == "1 hour, 59 minutes and 59 seconds.");
+        assert!(d.format_style(Style::Compact) == "1h 59m 59s");
+        assert!(d.format_style(Style::Approximate) == "about 2 hours");
+        assert!(d.format_style(Style::Spoken) == "1 hour 59 minutes and 59 seconds");
+    }
+
+    #[test]
+    fn test_format_with() {
+        let d = Duration::new(7199);
+        assert!(d.format_with(&FormatOptions::default()) == d.to_string());
+
+        let options = FormatOptions { style: Style::Compact, max_units: Some(2), ..FormatOptions::default() };
+        assert!(d.format_with(&options) == "1h 59m");
+        assert!(Duration::ZERO.format_with(&options) == "0s");
+
+        let options = FormatOptions { style: Style::Prose, max_units: Some(2), ..FormatOptions::default() };
+        assert!(d.format_with(&options) == "1 hour and 59 minutes.");
+        assert!(Duration::ZERO.format_with(&FormatOptions::default()) == "0 seconds.");
+        assert!(Duration::ZERO.format_with(&options) == Duration::ZERO.format_style(Style::Prose));
+    }
+
+    #[test]
+    fn test_format_labels() {
+        let sols = FormatOptions::default().label(TimeUnitKind::Days, UnitLabel::new("sol", "sols"));
+        let d = Duration::new(2 * 86_400 + 3600);
+        assert!(d.format_with(&sols) == "2 sols and 1 hour.");
+        assert!(Duration::new(86_400).format_with(&sols) == "1 sol.");
+        let secs = sols.clone().label(TimeUnitKind::Seconds, UnitLabel::new("sec", "secs"));
+        assert!(Duration::ZERO.format_with(&secs) == "0 secs.");
+
+        let options = sols.clone().label(TimeUnitKind::Hours, UnitLabel::fixed("h"));
+        assert!(d.format_with(&options) == "2 sols and 1 h.");
+        let compact = FormatOptions { style: Style::Compact, ..options.clone() };
+        assert!(d.format_with(&compact) == "2sols 1h");
+        assert!(Duration::new(60).format_with(&compact) == "1m");
+        assert!(Duration::ZERO.format_with(&compact) == "0s");
+
+        let approximate = FormatOptions { style: Style::Approximate, ..sols };
+        assert!(Duration::new(2 * 86_400 + 60).format_with(&approximate) == "about 2 sols");
+        let spoken = FormatOptions { style: Style::Spoken, ..options };
+        assert!(d.format_with(&spoken) == "2 sols and 1 h");
+    }
+
+    #[test]
+    fn test_format_spoken() {
+        let options = FormatOptions { style: Style::Spoken, spell_out: true, ..FormatOptions::default() };
+        let d = Duration::new(7199);
+        assert!(d.format_with(&options) == "one hour fifty nine minutes and fifty nine seconds");
+        assert!(Duration::new(35_344_799).format_with(&options).starts_with("one year forty four days one"));
+        assert!(Duration::new(60).format_with(&options) == "one minute");
+        assert!(Duration::ZERO.format_with(&options) == "zero seconds");
+
+        let options = FormatOptions { max_units: Some(2), spell_out: false, ..options };
+        assert!(d.format_with(&options) == "1 hour and 59 minutes");
+        assert!(Duration::new(3601).format_with(&options) == "1 hour and 1 second");
+        assert!(Duration::ZERO.format_with(&options) == "0 seconds");
+    }
+
+    #[test]
+    fn test_format_approximate() {
+        assert!(Duration::new(160).format_approximate() == "about 3 minutes");
+        assert!(Duration::new(149).format_approximate() == "about 2 minutes");
+        assert!(Duration::new(59 * 60 + 40).format_approximate() == "about 1 hour");
+        assert!(Duration::new(45).format_approximate() == "about 45 seconds");
+        assert!(Duration::new(1).format_approximate() == "about 1 second");
+        assert!(Duration::new(35_344_799).format_approximate() == "about 1 year");
+        assert!(Duration::new(0).format_approximate() == "less than a second");
+    }
+
+    #[test]
+    fn test_format_fit() {
+        let d = Duration::new(7199);
+        assert!(d.format_fit(40) == "1 hour, 59 minutes and 59 seconds");
+        assert!(d.format_fit(21) == "1h 59m 59s");
+        assert!(d.format_fit(9) == "~2h");
+
+        let d = Duration::new(7140);
+        assert!(d.format_fit(21) == "1 hour and 59 minutes");
+        assert!(d.format_fit(20) == "1h 59m");
+        assert!(d.format_fit(5) == "~2h");
+        assert!(d.format_fit(2) == "~…");
+        assert!(d.format_fit(0).is_empty());
+        assert!(Duration::new(35_344_799).format_fit(9) == "~1y 44d");
+        assert!(Duration::new(0).format_fit(3) == "0s");
+        assert!(Duration::new(0).format_fit(1) == "…");
+    }
+
+    #[test]
+    fn test_format_limited() {
+        let (prose, rest) = Duration::new(7199).format_limited(2);
+        assert!(prose == "1 hour and 59 minutes." && rest == Duration::new(59));
+
+        let (prose, rest) = Duration::new(35_344_799).format_limited(1);
+        assert!(prose == "1 year." && rest == Duration::new(3_808_799));
+
+        let (prose, rest) = Duration::new(61).format_limited(5);
+        assert!(prose == "1 minute and 1 second." && rest.is_zero());
+        let (prose, rest) = Duration::new(61).format_limited(0);
+        assert!(prose.is_empty() && rest == Duration::new(61));
+
+        let (prose, rest) = Duration::ZERO.format_limited(2);
+        assert!(prose == "0 seconds." && rest.is_zero());
+
+        let mut d = Duration::new(5);
+        d.years.amount = usize::MAX;
+        let (prose, rest) = d.format_limited(0);
+        assert!(prose.is_empty() && rest.years.amount == usize::MAX && rest.seconds.amount == 5);
+        let (_, rest) = d.format_limited(1);
+        assert!(rest == Duration::new(5));
+    }
+
+    #[test]
+    fn test_truncate() {
+        assert!(super::truncate("1 hour", 6) == "1 hour");
+        assert!(super::truncate("1 hour", 5) == "1 ho…");
+        assert!(super::truncate("1 hour", 1) == "…");
+    }
+
+    #[cfg(feature = "unicode-width")]
+    #[test]
+    fn test_truncate_wide_characters() {
+        assert!(super::truncate("１時間", 6) == "１時間");
+        assert!(super::truncate("１時間", 5) == "１時…");
+        assert!(super::truncate("１時間", 4) == "１…");
+    }
+
+    #[test]
+    fn test_as_prometheus() {
+        assert!(Duration::new(7199).as_prometheus() == "7199.000");
+        assert!(Duration::new(0).as_prometheus() == "0.000");
+    }
+
+    #[test]
+    fn test_from_prometheus() {
+        assert!(Duration::from_prometheus("7199.000") == Ok(Duration::new(7199)));
+        assert!(Duration::from_prometheus("7.1995e3") == Ok(Duration::new(7199)));
+        assert!(Duration::from_prometheus("-1.0") == Err(Error::InvalidFormat));
+        assert!(Duration::from_prometheus("NaN") == Err(Error::InvalidFormat));
+        assert!(Duration::from_prometheus("+Inf") == Err(Error::Overflow));
+        assert!(Duration::from_prometheus("1 hour") == Err(Error::InvalidFormat));
+    }
+}
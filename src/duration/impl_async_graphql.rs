@@ -0,0 +1,39 @@
+use async_graphql::{InputValueError, InputValueResult, Scalar, ScalarType, Value};
+
+use duration::Duration;
+
+/// A GraphQL `Duration` scalar, written as the compact string, ex) "1h 59m 59s", and read back
+/// in any format the `FromStr` implementation reads.
+#[Scalar(name = "Duration")]
+impl ScalarType for Duration {
+    fn parse(value: Value) -> InputValueResult<Self> {
+        match value {
+            Value::String(ref s) => s.parse().map_err(InputValueError::custom),
+            _ => Err(InputValueError::expected_type(value)),
+        }
+    }
+
+    fn is_valid(value: &Value) -> bool {
+        matches!(*value, Value::String(_))
+    }
+
+    fn to_value(&self) -> Value {
+        Value::String(self.format_compact())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use async_graphql::{ScalarType, Value};
+
+    use duration::Duration;
+
+    #[test]
+    fn test_async_graphql_scalar() {
+        assert!(Duration::new(7199).to_value() == Value::String("1h 59m 59s".to_string()));
+        let parsed = <Duration as ScalarType>::parse(Value::String("1 hour and 30 minutes".into()));
+        assert!(parsed.ok() == Some(Duration::new(5400)));
+        assert!(<Duration as ScalarType>::parse(Value::String("soon".into())).is_err());
+        assert!(<Duration as ScalarType>::parse(Value::from(30)).is_err());
+    }
+}
@@ -0,0 +1,58 @@
+use fluent_bundle::FluentArgs;
+
+use duration::Duration;
+
+impl Duration {
+    /// The amount of each unit as arguments for a Fluent message, named by the plural unit name,
+    /// ex) `$hours` and `$minutes`, along with `$total-seconds`. Every unit is set, zero ones
+    /// too, so that messages can select on `[0]` to leave them out.
+    pub fn fluent_args(&self) -> FluentArgs<'static> {
+        let mut args = FluentArgs::new();
+        for unit in self.iter_all_units() {
+            args.set(unit.kind.plural_name(), unit.amount);
+        }
+        args.set("total-seconds", self.as_secs());
+        args
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use fluent_bundle::{FluentBundle, FluentResource};
+
+    use duration::Duration;
+
+    const FTL: &str = "
+remaining = { $hours ->
+        [0] { $minutes ->
+            [one] one minute
+           *[other] { $minutes } minutes
+        }
+        [one] one hour
+       *[other] { $hours } hours
+    } left
+";
+
+    #[test]
+    fn test_fluent_args() {
+        let args = Duration::new(7199).fluent_args();
+        assert!(args.iter().count() == 6);
+        assert!(args.get("total-seconds").is_some());
+
+        let resource = FluentResource::try_new(FTL.to_string()).unwrap();
+        let mut bundle = FluentBundle::new(vec!["en-US".parse().unwrap()]);
+        bundle.set_use_isolating(false);
+        bundle.add_resource(resource).unwrap();
+        let message = bundle.get_message("remaining").and_then(|m| m.value()).unwrap();
+        let format = |secs: usize| {
+            let mut errors = vec![];
+            let args = Duration::new(secs).fluent_args();
+            bundle.format_pattern(message, Some(&args), &mut errors).into_owned()
+        };
+        assert!(format(3660) == "one hour left");
+        assert!(format(7199) == "one hour left");
+        assert!(format(7200) == "2 hours left");
+        assert!(format(60) == "one minute left");
+        assert!(format(150) == "2 minutes left");
+    }
+}
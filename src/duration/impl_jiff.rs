@@ -0,0 +1,117 @@
+use std::convert::TryFrom;
+
+use jiff::{SignedDuration, Span};
+
+use duration::{Duration, TimeUnitKind};
+use error::Error;
+
+impl TryFrom<Duration> for SignedDuration {
+    type Error = Error;
+
+    /// Converts the total amount of seconds, returning
+    /// [`Error::Overflow`](../error/enum.Error.html) if it exceeds `i64::MAX`.
+    fn try_from(d: Duration) -> Result<Self, Self::Error> {
+        i64::try_from(d.as_secs())
+            .map(SignedDuration::from_secs)
+            .map_err(|_| Error::Overflow)
+    }
+}
+
+impl TryFrom<SignedDuration> for Duration {
+    type Error = Error;
+
+    /// Converts whole seconds, truncating below a second. Negative durations are an
+    /// [`Error::Overflow`](../error/enum.Error.html).
+    fn try_from(d: SignedDuration) -> Result<Self, Self::Error> {
+        usize::try_from(d.as_secs())
+            .map(Duration::new)
+            .map_err(|_| Error::Overflow)
+    }
+}
+
+impl TryFrom<Duration> for Span {
+    type Error = Error;
+
+    /// Keeps each unit of the duration as the same unit of the span, ex) 1 year and 2 days
+    /// become `1y 2d`, so that humanized spans read the same. A year of the span is a calendar
+    /// year though, so adding it to a date moves by a whole year rather than by 365 days.
+    /// Amounts beyond jiff's ranges are an [`Error::Overflow`](../error/enum.Error.html).
+    fn try_from(d: Duration) -> Result<Self, Self::Error> {
+        let amount = |amount: usize| i64::try_from(amount).map_err(|_| Error::Overflow);
+        let (years, days, hours, minutes, seconds) = (
+            amount(d.years.amount)?,
+            amount(d.days.amount)?,
+            amount(d.hours.amount)?,
+            amount(d.minutes.amount)?,
+            amount(d.seconds.amount)?,
+        );
+        Span::new()
+            .try_years(years)
+            .and_then(|span| span.try_days(days))
+            .and_then(|span| span.try_hours(hours))
+            .and_then(|span| span.try_minutes(minutes))
+            .and_then(|span| span.try_seconds(seconds))
+            .map_err(|_| Error::Overflow)
+    }
+}
+
+impl TryFrom<Span> for Duration {
+    type Error = Error;
+
+    /// Adds up the units of the span with years of 365 days, weeks of 7 days and days of 24
+    /// hours, truncating below a second. Months have no fixed length, so spans with months are
+    /// an [`Error::InvalidFormat`](../error/enum.Error.html), and negative spans are an
+    /// [`Error::Overflow`](../error/enum.Error.html).
+    fn try_from(span: Span) -> Result<Self, Self::Error> {
+        if span.get_months() != 0 {
+            return Err(Error::InvalidFormat);
+        }
+        if span.is_negative() {
+            return Err(Error::Overflow);
+        }
+        let secs = |amount: i64, kind: TimeUnitKind| amount as i128 * kind.as_seconds() as i128;
+        let nanos = (secs(span.get_years().into(), TimeUnitKind::Years)
+            + secs(span.get_weeks().into(), TimeUnitKind::Days) * 7
+            + secs(span.get_days().into(), TimeUnitKind::Days)
+            + secs(span.get_hours().into(), TimeUnitKind::Hours)
+            + secs(span.get_minutes(), TimeUnitKind::Minutes)
+            + secs(span.get_seconds(), TimeUnitKind::Seconds))
+            * 1_000_000_000
+            + span.get_milliseconds() as i128 * 1_000_000
+            + span.get_microseconds() as i128 * 1_000
+            + span.get_nanoseconds() as i128;
+        usize::try_from(nanos / 1_000_000_000)
+            .map(Duration::new)
+            .map_err(|_| Error::Overflow)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::TryFrom;
+
+    use jiff::{SignedDuration, Span, ToSpan};
+
+    use duration::Duration;
+    use error::Error;
+
+    #[test]
+    fn test_jiff_signed_duration() {
+        let d = Duration::new(7199);
+        assert!(SignedDuration::try_from(d) == Ok(SignedDuration::from_secs(7199)));
+        assert!(Duration::try_from(SignedDuration::from_millis(7_199_999)) == Ok(d));
+        assert!(Duration::try_from(SignedDuration::from_secs(-1)) == Err(Error::Overflow));
+    }
+
+    #[test]
+    fn test_jiff_span() {
+        let d = Duration::new(31_536_000 + 2 * 86_400 + 3661);
+        let span = Span::try_from(d).unwrap();
+        assert!(span.fieldwise() == 1.year().days(2).hours(1).minutes(1).seconds(1).fieldwise());
+        assert!(Duration::try_from(span) == Ok(d));
+
+        assert!(Duration::try_from(2.weeks().milliseconds(1500)) == Ok(Duration::new(14 * 86_400 + 1)));
+        assert!(Duration::try_from(1.month()) == Err(Error::InvalidFormat));
+        assert!(Duration::try_from(-1.hour()) == Err(Error::Overflow));
+    }
+}
@@ -0,0 +1,38 @@
+use juniper::graphql_scalar;
+
+/// A GraphQL `Duration` scalar, written as the compact string, ex) "1h 59m 59s", and read back
+/// in any format the `FromStr` implementation reads.
+#[graphql_scalar]
+#[graphql(
+    name = "Duration",
+    with = duration_scalar,
+    to_output_with = Duration::format_compact,
+    parse_token(String)
+)]
+type Duration = ::duration::Duration;
+
+mod duration_scalar {
+    use super::Duration;
+
+    pub(super) fn from_input(s: &str) -> Result<Duration, Box<str>> {
+        s.parse().map_err(|e| format!("Failed to parse `Duration`: {}", e).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use juniper::{graphql_input_value, FromInputValue, InputValue, ToInputValue};
+
+    use duration::Duration;
+
+    #[test]
+    fn test_juniper_scalar() {
+        let input: InputValue = graphql_input_value!("1 hour and 30 minutes");
+        assert!(Duration::from_input_value(&input).ok() == Some(Duration::new(5400)));
+        let input: InputValue = graphql_input_value!("soon");
+        assert!(Duration::from_input_value(&input).is_err());
+
+        let output: InputValue = Duration::new(7199).to_input_value();
+        assert!(output == graphql_input_value!("1h 59m 59s"));
+    }
+}
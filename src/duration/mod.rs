@@ -1,12 +1,38 @@
+use std::convert::TryFrom;
+use std::cmp::Ordering;
+use std::collections::BTreeMap;
 use std::fmt;
-use std::ops::Deref;
-
-#[derive(PartialEq, Clone, Copy, Debug)]
+use std::hash::{Hash, Hasher};
+use std::iter;
+use std::ops::{Add, Deref};
+use std::str::FromStr;
+use std::time::Duration as StdDuration;
+
+use error::Error;
+use join::{join_human, JoinOptions};
+
+#[cfg(feature = "async-graphql")]
+mod impl_async_graphql;
+#[cfg(feature = "fluent")]
+mod impl_fluent;
+mod format;
+pub use self::format::{FormatOptions, Style, UnitLabel, STABLE_GRAMMAR_VERSION};
+#[cfg(feature = "jiff")]
+mod impl_jiff;
+#[cfg(feature = "juniper")]
+mod impl_juniper;
+#[cfg(feature = "tracing")]
+mod trace;
+mod words;
+
+#[derive(PartialEq, Eq, PartialOrd, Ord, Clone, Copy, Debug)]
 /// Wrapper type for usize to represent raw seconds before converted into a
-/// [`Duration`](struct.Duration.html).
-struct RawSeconds(usize);
+/// [`Duration`](struct.Duration.html). Transparent over `usize`, so it can cross FFI boundaries
+/// as a plain integer.
+#[repr(transparent)]
+pub struct Seconds(pub usize);
 
-impl Deref for RawSeconds {
+impl Deref for Seconds {
     type Target = usize;
 
     fn deref(&self) -> &Self::Target {
@@ -14,30 +40,39 @@ impl Deref for RawSeconds {
     }
 }
 
-impl <'a> From<&'a TimeUnit> for RawSeconds {
+impl <'a> From<&'a TimeUnit> for Seconds {
     /// Convert a [`TimeUnit`](struct.TimeUnit.html) into seconds based on its `kind` and `amount`
-    /// fields.
-    fn from(t: &'a TimeUnit) -> RawSeconds {
-        match t.kind {
-            TimeUnitKind::Seconds => RawSeconds(t.amount),
-            TimeUnitKind::Minutes => RawSeconds(t.amount * 60),
-            TimeUnitKind::Hours => RawSeconds(t.amount * 60 * 60),
-            TimeUnitKind::Days => RawSeconds(t.amount * 60 * 60 * 24),
-            TimeUnitKind::Years => RawSeconds(t.amount * 60 * 60 * 24 * 365)
-        }
+    /// fields, saturating at `usize::MAX` like [`from_unit`](#method.from_unit).
+    fn from(t: &'a TimeUnit) -> Seconds {
+        Seconds::from_unit(t.amount, t.kind)
     }
 }
 
-impl From<Duration> for RawSeconds {
-    /// Converts a full [`Duration`](struct.Duration.html) back into seconds.
-    fn from(d: Duration) -> RawSeconds {
-        RawSeconds(d.iter_units()
-            .map(RawSeconds::from)
-            .fold(0, |acc, ref n| acc + n.0))
+impl Seconds {
+    /// Converts `amount` units of `kind` into seconds, ex) 2 hours into 7200 seconds. Saturates
+    /// at `usize::MAX`, use [`checked_from`](#method.checked_from) to detect overflow instead.
+    pub fn from_unit(amount: usize, kind: TimeUnitKind) -> Self {
+        Seconds(amount.saturating_mul(kind.as_seconds()))
+    }
+
+    /// Like the `From<&TimeUnit>` conversion, but returns `None` instead of overflowing.
+    pub fn checked_from(t: &TimeUnit) -> Option<Seconds> {
+        t.amount.checked_mul(t.kind.as_seconds()).map(Seconds)
     }
 }
 
-#[derive(PartialEq, Clone, Copy)]
+impl From<Duration> for Seconds {
+    /// Converts a full [`Duration`](struct.Duration.html) back into seconds, saturating at
+    /// `usize::MAX`.
+    fn from(d: Duration) -> Seconds {
+        Seconds(d.total_seconds())
+    }
+}
+
+/// The granularity of a [`TimeUnit`](struct.TimeUnit.html). The discriminants are stable, so
+/// that FFI and storage layers can keep a unit as a `u8`, see `TryFrom<u8>`. The enum is kept
+/// exhaustive, as matching on every unit is what formatting code does.
+#[derive(PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy, Debug)]
 pub enum TimeUnitKind {
     Seconds = 0,
     Minutes = 1,
@@ -46,6 +81,103 @@ pub enum TimeUnitKind {
     Years = 4,
 }
 
+/// Every [`TimeUnitKind`](enum.TimeUnitKind.html), from smallest to largest.
+pub(crate) const KINDS: [TimeUnitKind; 5] = [
+    TimeUnitKind::Seconds,
+    TimeUnitKind::Minutes,
+    TimeUnitKind::Hours,
+    TimeUnitKind::Days,
+    TimeUnitKind::Years,
+];
+
+impl TimeUnitKind {
+    /// The amount of seconds in one unit of `Self`, ex) 3600 for hours.
+    pub fn as_seconds(self) -> usize {
+        match self {
+            TimeUnitKind::Seconds => 1,
+            TimeUnitKind::Minutes => 60,
+            TimeUnitKind::Hours => 60 * 60,
+            TimeUnitKind::Days => 60 * 60 * 24,
+            TimeUnitKind::Years => 60 * 60 * 24 * 365,
+        }
+    }
+
+    /// The singular, long name of `Self`.
+    pub(crate) fn name(self) -> &'static str {
+        match self {
+            TimeUnitKind::Years => "year",
+            TimeUnitKind::Days => "day",
+            TimeUnitKind::Hours => "hour",
+            TimeUnitKind::Minutes => "minute",
+            TimeUnitKind::Seconds => "second",
+        }
+    }
+
+    /// The single letter abbreviation of `Self`, ex) "h".
+    pub(crate) fn symbol(self) -> &'static str {
+        match self {
+            TimeUnitKind::Years => "y",
+            TimeUnitKind::Days => "d",
+            TimeUnitKind::Hours => "h",
+            TimeUnitKind::Minutes => "m",
+            TimeUnitKind::Seconds => "s",
+        }
+    }
+
+    /// The plural, long name of `Self`.
+    pub(crate) fn plural_name(self) -> &'static str {
+        match self {
+            TimeUnitKind::Years => "years",
+            TimeUnitKind::Days => "days",
+            TimeUnitKind::Hours => "hours",
+            TimeUnitKind::Minutes => "minutes",
+            TimeUnitKind::Seconds => "seconds",
+        }
+    }
+}
+
+impl fmt::Display for TimeUnitKind {
+    /// Formats `Self` as its singular, long name, ex) "hour".
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        f.write_str(self.name())
+    }
+}
+
+impl FromStr for TimeUnitKind {
+    type Err = Error;
+
+    /// Parses a unit name: the long name, its plural or a common abbreviation, ex) "hour",
+    /// "hours", "hrs", "hr" or "h".
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "s" | "sec" | "secs" | "second" | "seconds" => Ok(TimeUnitKind::Seconds),
+            "m" | "min" | "mins" | "minute" | "minutes" => Ok(TimeUnitKind::Minutes),
+            "h" | "hr" | "hrs" | "hour" | "hours" => Ok(TimeUnitKind::Hours),
+            "d" | "day" | "days" => Ok(TimeUnitKind::Days),
+            "y" | "yr" | "yrs" | "year" | "years" => Ok(TimeUnitKind::Years),
+            _ => Err(Error::UnknownUnit),
+        }
+    }
+}
+
+impl TryFrom<u8> for TimeUnitKind {
+    type Error = Error;
+
+    /// Reads a discriminant back, ex) 2 -> `Hours`. Unassigned values are an unknown unit.
+    fn try_from(discriminant: u8) -> Result<Self, Self::Error> {
+        KINDS
+            .get(discriminant as usize)
+            .cloned()
+            .ok_or(Error::UnknownUnit)
+    }
+}
+
+impl From<TimeUnitKind> for u8 {
+    fn from(kind: TimeUnitKind) -> u8 {
+        kind as u8
+    }
+}
+
 #[derive(PartialEq, Clone, Copy)]
 pub struct TimeUnit {
     /// The granularity of the amount of time.
@@ -55,11 +187,33 @@ pub struct TimeUnit {
 }
 
 impl TimeUnit {
-    fn new(kind: TimeUnitKind, amount: usize) -> Self {
-        TimeUnit {
-            kind: kind,
-            amount: amount,
-        }
+    const fn new(kind: TimeUnitKind, amount: usize) -> Self {
+        TimeUnit { kind, amount }
+    }
+
+    /// The amount of seconds represented, which cannot overflow.
+    fn total(&self) -> u128 {
+        self.amount as u128 * self.kind.as_seconds() as u128
+    }
+}
+
+impl PartialOrd for TimeUnit {
+    /// Orders by the amount of seconds represented, then by `kind` so that the ordering agrees
+    /// with equality, ex) 60 minutes < 1 hour < 61 minutes.
+    fn partial_cmp(&self, other: &TimeUnit) -> Option<Ordering> {
+        let ord = self.total().cmp(&other.total()).then(self.kind.partial_cmp(&other.kind)?);
+        Some(ord)
+    }
+}
+
+impl Add for TimeUnit {
+    type Output = Duration;
+
+    /// Adds both units in seconds and returns the normalized [`Duration`](struct.Duration.html),
+    /// ex) 30 minutes + 45 minutes = 1 hour and 15 minutes. Panics if the sum exceeds
+    /// `usize::MAX` seconds, like `Duration + Duration`.
+    fn add(self, rhs: TimeUnit) -> Duration {
+        Duration::new(usize::try_from(self.total() + rhs.total()).expect("duration overflow"))
     }
 }
 
@@ -67,13 +221,8 @@ impl fmt::Display for TimeUnit {
     /// Formats `Self` according to: `{amount} {kind}[s if n > 1]`.
     fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
         let mut s: String = self.amount.to_string();
-        s.push_str(match self.kind {
-            TimeUnitKind::Years => " year",
-            TimeUnitKind::Days => " day",
-            TimeUnitKind::Hours => " hour",
-            TimeUnitKind::Minutes => " minute",
-            TimeUnitKind::Seconds => " second",
-        });
+        s.push(' ');
+        s.push_str(self.kind.name());
 
         if self.amount > 1 {
             s.push('s');
@@ -83,9 +232,57 @@ impl fmt::Display for TimeUnit {
     }
 }
 
+/// Selects which units of a [`Duration`](struct.Duration.html) are emitted as structured data.
+#[derive(PartialEq, Clone, Copy, Debug)]
+pub enum UnitFilter {
+    /// Only units with an amount above zero.
+    NonZero,
+    /// Every unit, including those with an amount of zero.
+    All,
+}
+
+/// The prose and the structured form of a [`Duration`](struct.Duration.html), built together by
+/// [`to_annotated`](struct.Duration.html#method.to_annotated).
+#[derive(PartialEq, Clone, Debug)]
+pub struct Annotated {
+    /// The `Display` prose, ex) "1 hour and 30 minutes.".
+    pub text: String,
+    pub total_seconds: u64,
+    /// The non-zero units from largest to smallest, ex) `[(Hours, 1), (Minutes, 30)]`.
+    pub components: Vec<(TimeUnitKind, u64)>,
+}
+
+/// Selects how amounts which fall between two whole seconds are rounded.
+#[derive(PartialEq, Clone, Copy, Debug)]
+pub enum Rounding {
+    /// Down to the previous whole second.
+    Down,
+    /// To the nearest whole second, halves up.
+    Nearest,
+    /// Up to the next whole second.
+    Up,
+}
+
+impl Rounding {
+    /// Rounds `numerator / denominator`. `denominator` must not be zero.
+    pub(crate) fn div(self, numerator: u128, denominator: u128) -> u128 {
+        let (quotient, remainder) = (numerator / denominator, numerator % denominator);
+        let round_up = match self {
+            Rounding::Down => false,
+            Rounding::Nearest => remainder * 2 >= denominator,
+            Rounding::Up => remainder > 0,
+        };
+        quotient + if round_up { 1 } else { 0 }
+    }
+}
+
 /// Represents parts of a duration with fields of various granularity. Fields are represented by
 /// [`TimeUnit`](struct.TimeUnit.html).
-#[derive(PartialEq, Clone, Copy)]
+///
+/// Equality, ordering and hashing go by the total amount of seconds, which is summed from the
+/// fields in O(1) without allocating, so that units set by hand compare like their normalized
+/// form, ex) 75 minutes == 1 hour and 15 minutes.
+#[derive(Clone, Copy)]
 pub struct Duration {
     pub seconds: TimeUnit,
     pub minutes: TimeUnit,
@@ -95,12 +292,107 @@ pub struct Duration {
 }
 
 impl Duration {
+    /// The empty duration.
+    pub const ZERO: Duration = Duration::new(0);
+    /// The smallest duration, equal to [`ZERO`](struct.Duration.html#associatedconstant.ZERO).
+    pub const MIN: Duration = Duration::ZERO;
+    /// The largest duration `new` can derive.
+    pub const MAX: Duration = Duration::new(usize::MAX);
+
     /// From seconds (in usize), derive a fine-grained [`Duration`](struct.Duration.html).
-    pub fn new(seconds: usize) -> Self {
-        RawSeconds(seconds).into()
+    pub const fn new(mut seconds: usize) -> Self {
+        let mut duration = Duration::new_zeroed();
+
+        duration.years.amount = seconds / (60 * 60 * 24 * 365);
+        seconds %= 60 * 60 * 24 * 365;
+
+        duration.days.amount = seconds / (60 * 60 * 24);
+        seconds %= 60 * 60 * 24;
+
+        duration.hours.amount = seconds / (60 * 60);
+        seconds %= 60 * 60;
+
+        duration.minutes.amount = seconds / 60;
+        seconds %= 60;
+
+        duration.seconds.amount = seconds;
+
+        duration
+    }
+
+    /// Whether both durations amount to the same total of seconds, ex) 75 minutes and 1 hour
+    /// and 15 minutes. This is what `==` compares.
+    pub fn eq_total(&self, other: &Duration) -> bool {
+        self.total() == other.total()
+    }
+
+    /// Whether both durations hold the same amount in each unit, so that 75 minutes set by hand
+    /// differs from 1 hour and 15 minutes. Durations built by `new` are normalized, for which
+    /// this agrees with [`eq_total`](struct.Duration.html#method.eq_total).
+    pub fn eq_components(&self, other: &Duration) -> bool {
+        self.iter_all_units()
+            .zip(other.iter_all_units())
+            .all(|(a, b)| a.amount == b.amount)
+    }
+
+    /// Whether `self` lies within `low` and `high`, both inclusive, ex) SLA bounds. Ranges work
+    /// as well through the ordering, ex) `(Duration::new(60)..Duration::new(3600)).contains(&d)`.
+    pub fn is_between(&self, low: Duration, high: Duration) -> bool {
+        low <= *self && *self <= high
+    }
+
+    /// Whether every unit of `Self` amounts to zero.
+    pub fn is_zero(&self) -> bool {
+        self.iter_units().next().is_none()
     }
 
-    fn new_zeroed() -> Self {
+    /// Whether any unit of `Self` amounts to more than zero.
+    pub fn is_positive(&self) -> bool {
+        !self.is_zero()
+    }
+
+    /// Converts `Self` into a [`std::time::Duration`], returning
+    /// [`Error::Overflow`](../error/enum.Error.html) if the total amount of seconds does not fit.
+    pub fn try_into_std(&self) -> Result<StdDuration, Error> {
+        let secs = self.checked_total_seconds().ok_or(Error::Overflow)?;
+        Ok(StdDuration::from_secs(secs as u64))
+    }
+
+    /// Builds a [`Duration`](struct.Duration.html) from a [`std::time::Duration`], returning
+    /// [`Error::Overflow`](../error/enum.Error.html) if its seconds do not fit in a `usize`.
+    /// Sub-second precision is truncated.
+    pub fn try_from_std(d: StdDuration) -> Result<Self, Error> {
+        usize::try_from(d.as_secs())
+            .map(Duration::new)
+            .map_err(|_| Error::Overflow)
+    }
+
+    /// The total amount of seconds in `Self`, ex) 7199 for "1 hour, 59 minutes and 59 seconds.".
+    /// This is O(1) and does not allocate: it is computed from the five unit fields in place.
+    /// Units set by hand beyond what `new` derives saturate at `u64::MAX`.
+    pub fn as_secs(&self) -> u64 {
+        u64::try_from(self.total()).unwrap_or(u64::MAX)
+    }
+
+    /// The exact total amount of seconds, which cannot overflow: five units of at most
+    /// `usize::MAX` times a year's seconds fit in a `u128`.
+    fn total(&self) -> u128 {
+        self.iter_all_units()
+            .map(TimeUnit::total)
+            .sum()
+    }
+
+    /// The total amount of seconds in `Self`, if it fits in a `usize`.
+    pub(crate) fn checked_total_seconds(&self) -> Option<usize> {
+        usize::try_from(self.total()).ok()
+    }
+
+    /// The total amount of seconds in `Self`, saturating at `usize::MAX`.
+    pub(crate) fn total_seconds(&self) -> usize {
+        self.checked_total_seconds().unwrap_or(usize::MAX)
+    }
+
+    const fn new_zeroed() -> Self {
         Duration {
             seconds: TimeUnit::new(TimeUnitKind::Seconds, 0),
             minutes: TimeUnit::new(TimeUnitKind::Minutes, 0),
@@ -110,37 +402,89 @@ impl Duration {
         }
     }
 
-    fn iter_units(&self) -> impl Iterator<Item = &TimeUnit> {
-        vec![
-            &self.years,
-            &self.days,
-            &self.hours,
-            &self.minutes,
-            &self.seconds,
-        ].into_iter()
-            .filter(|unit| unit.amount > 0)
+    /// Lists the units of `Self` from largest to smallest as `(plural name, amount)` pairs, ex)
+    /// `[("years", 1), ("days", 44), ("hours", 1), ("minutes", 59), ("seconds", 59)]`.
+    pub fn to_pairs(&self, filter: UnitFilter) -> Vec<(&'static str, usize)> {
+        self.iter_all_units()
+            .filter(|unit| filter == UnitFilter::All || unit.amount > 0)
+            .map(|unit| (unit.kind.plural_name(), unit.amount))
+            .collect()
+    }
+
+    /// Like [`to_pairs`](struct.Duration.html#method.to_pairs), but keyed by plural unit name.
+    pub fn to_map(&self, filter: UnitFilter) -> BTreeMap<&'static str, usize> {
+        self.to_pairs(filter).into_iter().collect()
+    }
+
+    /// Formats `Self` as prose along with its total seconds and units, for APIs which return
+    /// both a display string and structured data.
+    pub fn to_annotated(&self) -> Annotated {
+        let units = self.iter_units().collect::<Vec<&TimeUnit>>();
+        let text = if units.is_empty() {
+            "0 seconds.".to_string()
+        } else {
+            join_human(units.iter(), &JoinOptions::default())
+        };
+        Annotated {
+            text,
+            total_seconds: self.total_seconds() as u64,
+            components: units.iter().map(|unit| (unit.kind, unit.amount as u64)).collect(),
+        }
+    }
+
+    fn iter_all_units(&self) -> impl Iterator<Item = &TimeUnit> {
+        iter::once(&self.years)
+            .chain(iter::once(&self.days))
+            .chain(iter::once(&self.hours))
+            .chain(iter::once(&self.minutes))
+            .chain(iter::once(&self.seconds))
+    }
+
+    pub(crate) fn iter_units(&self) -> impl Iterator<Item = &TimeUnit> {
+        self.iter_all_units().filter(|unit| unit.amount > 0)
     }
 }
 
-impl From<RawSeconds> for Duration {
-    fn from(mut rs: RawSeconds) -> Duration {
-        let mut duration = Duration::new_zeroed();
+impl PartialEq for Duration {
+    /// Compares like [`eq_total`](struct.Duration.html#method.eq_total).
+    fn eq(&self, other: &Duration) -> bool {
+        self.eq_total(other)
+    }
+}
 
-        duration.years.amount = *rs / (60 * 60 * 24 * 365);
-        rs.0 = *rs % (60 * 60 * 24 * 365);
+impl Eq for Duration {}
 
-        duration.days.amount = *rs / (60 * 60 * 24);
-        rs.0 = *rs % (60 * 60 * 24);
+impl PartialOrd for Duration {
+    fn partial_cmp(&self, other: &Duration) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
 
-        duration.hours.amount = *rs / (60 * 60);
-        rs.0 = *rs % (60 * 60);
+impl Ord for Duration {
+    fn cmp(&self, other: &Duration) -> Ordering {
+        self.total().cmp(&other.total())
+    }
+}
 
-        duration.minutes.amount = *rs / (60);
-        rs.0 = *rs % (60);
+impl Hash for Duration {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.total().hash(state);
+    }
+}
 
-        duration.seconds.amount = rs.0;
+impl Add for Duration {
+    type Output = Duration;
 
-        duration
+    /// Adds both durations in seconds and returns the normalized sum. Panics if the sum exceeds
+    /// `usize::MAX` seconds.
+    fn add(self, rhs: Duration) -> Duration {
+        Duration::new(usize::try_from(self.total() + rhs.total()).expect("duration overflow"))
+    }
+}
+
+impl From<Seconds> for Duration {
+    fn from(rs: Seconds) -> Duration {
+        Duration::new(rs.0)
     }
 }
 
@@ -149,36 +493,54 @@ impl fmt::Display for Duration {
     /// * ex) 3600 seconds -> "1 hour."
     /// * ex) 3599 seconds -> "59 minutes and 59 seconds."
     /// * ex) 7199 seconds -> "1 hour, 59 minutes and 59 seconds."
-    ///     Note) Say there was 1 day additionally to this duration: "1 day, 1 hour, 59 minutes and
-    ///     59 seconds.". So, "_x<sub>1</sub>_ _y<sub>1</sub>_, ..., _x<sub>n</sub>_
-    ///     _y<sub>n</sub>_, _a_ _b_ and _c_ _d_".
+    ///   Note) Say there was 1 day additionally to this duration: "1 day, 1 hour, 59 minutes and
+    ///   59 seconds.". So, "_x<sub>1</sub>_ _y<sub>1</sub>_, ..., _x<sub>n</sub>_
+    ///   _y<sub>n</sub>_, _a_ _b_ and _c_ _d_".
+    /// * ex) 0 seconds -> "0 seconds."
+    ///
+    /// The output reads back through `FromStr`, also with
+    /// [`Strictness::Strict`](../parse/enum.Strictness.html#variant.Strict).
     fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        if self.is_zero() {
+            return f.write_str("0 seconds.");
+        }
         let units = self.iter_units().collect::<Vec<&TimeUnit>>();
+        f.write_str(&join_human(units, &JoinOptions::default()))
+    }
+}
 
-        let s = match units.as_slice() {
-            &[] => "".to_string(),
-            &[only_unit] => format!("{}.", only_unit),
-            &[unit_one, unit_two] => format!("{} and {}.", unit_one, unit_two),
-            all_units => {
-                let mut s = String::new();
-                for unit in all_units.iter().take(all_units.len() - 2) {
-                    s.push_str(&format!("{}, ", unit));
-                }
-                s.push_str(&format!(
-                    "{} and {}.",
-                    all_units[all_units.len() - 2],
-                    all_units[all_units.len() - 1]
-                ));
-                s
-            }
-        };
-        f.write_str(&s)
+impl fmt::Debug for Duration {
+    /// Formats `Self` as its compact form, ex) "Duration(1h 59m 59s)". The alternate form `{:#?}`
+    /// is meant for logs and carries the total seconds as well, ex) "1h59m59s (7199s)".
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        if !f.alternate() {
+            return write!(f, "Duration({})", self.format_compact());
+        }
+        if self.is_zero() {
+            f.write_str("0s")?;
+        }
+        for unit in self.iter_units() {
+            write!(f, "{}{}", unit.amount, unit.kind.symbol())?;
+        }
+        write!(f, " ({}s)", self.total())
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use duration::{Duration, TimeUnit, TimeUnitKind, RawSeconds};
+    use duration::{Annotated, Duration, TimeUnit, TimeUnitKind, Seconds, UnitFilter, KINDS};
+    use error::Error;
+    use std::convert::TryFrom;
+    use std::time::Duration as StdDuration;
+
+    #[test]
+    fn test_time_unit_kind_discriminant() {
+        for &kind in KINDS.iter() {
+            assert!(TimeUnitKind::try_from(u8::from(kind)) == Ok(kind));
+        }
+        assert!(u8::from(TimeUnitKind::Hours) == 2);
+        assert!(TimeUnitKind::try_from(5) == Err(Error::UnknownUnit));
+    }
 
     #[test]
     fn test_partial_eq_timeunit() {
@@ -190,6 +552,32 @@ mod tests {
         assert!(TimeUnit::new(TimeUnitKind::Hours, 5) != TimeUnit::new(TimeUnitKind::Hours, 2));
     }
 
+    #[test]
+    fn test_partial_ord_timeunit() {
+        assert!(TimeUnit::new(TimeUnitKind::Minutes, 59) < TimeUnit::new(TimeUnitKind::Hours, 1));
+        assert!(TimeUnit::new(TimeUnitKind::Minutes, 60) < TimeUnit::new(TimeUnitKind::Hours, 1));
+        assert!(TimeUnit::new(TimeUnitKind::Minutes, 61) > TimeUnit::new(TimeUnitKind::Hours, 1));
+        assert!(TimeUnit::new(TimeUnitKind::Days, 2) >= TimeUnit::new(TimeUnitKind::Days, 2));
+
+        let most_years = TimeUnit::new(TimeUnitKind::Years, usize::MAX);
+        assert!(most_years > TimeUnit::new(TimeUnitKind::Seconds, 1));
+        assert!(most_years > TimeUnit::new(TimeUnitKind::Seconds, usize::MAX));
+        assert!(TimeUnit::new(TimeUnitKind::Years, usize::MAX - 1) < most_years);
+    }
+
+    #[test]
+    fn test_add_timeunit() {
+        let sum = TimeUnit::new(TimeUnitKind::Minutes, 30) + TimeUnit::new(TimeUnitKind::Minutes, 45);
+        assert!(sum == Duration::new(4500));
+        assert!(format!("{}", sum) == "1 hour and 15 minutes.");
+
+        let years = TimeUnit::new(TimeUnitKind::Years, usize::MAX / 31_536_000);
+        let seconds = TimeUnit::new(TimeUnitKind::Seconds, usize::MAX % 31_536_000);
+        assert!(years + seconds == Duration::new(usize::MAX));
+        let max = TimeUnit::new(TimeUnitKind::Seconds, usize::MAX);
+        assert!(max + TimeUnit::new(TimeUnitKind::Years, 0) == Duration::new(usize::MAX));
+    }
+
     #[test]
     fn test_display_timeunit() {
         let mut tu_secs = TimeUnit::new(TimeUnitKind::Seconds, 1);
@@ -215,6 +603,22 @@ mod tests {
         assert!(format!("{}", tu_years) == "2 years");
     }
 
+    #[test]
+    fn test_display_timeunitkind() {
+        assert!(format!("{}", TimeUnitKind::Seconds) == "second");
+        assert!(format!("{}", TimeUnitKind::Years) == "year");
+    }
+
+    #[test]
+    fn test_timeunitkind_from_str() {
+        assert!("h".parse::<TimeUnitKind>() == Ok(TimeUnitKind::Hours));
+        assert!("hr".parse::<TimeUnitKind>() == Ok(TimeUnitKind::Hours));
+        assert!("hours".parse::<TimeUnitKind>() == Ok(TimeUnitKind::Hours));
+        assert!("mins".parse::<TimeUnitKind>() == Ok(TimeUnitKind::Minutes));
+        assert!("day".parse::<TimeUnitKind>() == Ok(TimeUnitKind::Days));
+        assert!("fortnight".parse::<TimeUnitKind>() == Err(Error::UnknownUnit));
+    }
+
     #[test]
     fn test_duration_new() {
         let one_hour = Duration::new(3600);
@@ -243,12 +647,167 @@ mod tests {
 
         let five_units = Duration::new(35_344_799);
         assert!(format!("{}", five_units) == "1 year, 44 days, 1 hour, 59 minutes and 59 seconds.");
+
+        assert!(format!("{}", Duration::ZERO) == "0 seconds.");
+    }
+
+    #[test]
+    fn test_duration_debug() {
+        assert!(format!("{:?}", Duration::new(7199)) == "Duration(1h 59m 59s)");
+        assert!(format!("{:#?}", Duration::new(7199)) == "1h59m59s (7199s)");
+        assert!(format!("{:#?}", Duration::ZERO) == "0s (0s)");
+    }
+
+    #[test]
+    fn test_seconds_from_unit() {
+        assert!(Seconds::from_unit(2, TimeUnitKind::Hours) == Seconds(7200));
+        assert!(*Seconds::from(&TimeUnit::new(TimeUnitKind::Days, 1)) == 86_400);
+        assert!(Seconds::checked_from(&TimeUnit::new(TimeUnitKind::Years, usize::MAX)).is_none());
+        assert!(Seconds::from_unit(usize::MAX, TimeUnitKind::Years) == Seconds(usize::MAX));
+        assert!(*Seconds::from(&TimeUnit::new(TimeUnitKind::Years, usize::MAX)) == usize::MAX);
+        assert!(Duration::from(Seconds(7199)) == Duration::new(7199));
+    }
+
+    #[test]
+    fn test_duration_constants() {
+        assert!(Duration::ZERO == Duration::new(0));
+        assert!(Duration::MIN == Duration::ZERO);
+        assert!(Duration::MAX == Duration::new(usize::MAX));
+    }
+
+    #[test]
+    fn test_duration_predicates() {
+        assert!(Duration::ZERO.is_zero() && !Duration::ZERO.is_positive());
+        assert!(Duration::new(1).is_positive() && !Duration::new(1).is_zero());
+
+        let mut d = Duration::ZERO;
+        d.days.amount = 1;
+        assert!(d.is_positive());
+    }
+
+    #[test]
+    fn test_add_duration() {
+        let sum = Duration::new(3599) + Duration::new(1);
+        assert!(sum == Duration::new(3600));
+        assert!(sum.hours.amount == 1 && sum.minutes.amount == 0);
+
+        let mut denormalized = Duration::ZERO;
+        denormalized.years.amount = 1;
+        denormalized.seconds.amount = usize::MAX - 31_536_000 - 1;
+        assert!(denormalized + Duration::new(1) == Duration::new(usize::MAX));
+    }
+
+    #[test]
+    fn test_total_seconds_saturates() {
+        let mut d = Duration::ZERO;
+        d.years.amount = usize::MAX;
+        assert!(d.total_seconds() == usize::MAX);
+        d.years.amount = 2;
+        assert!(d.total_seconds() == 2 * 31_536_000);
     }
 
     #[test]
     fn test_duration_2_rawsecs() {
         let five_units = Duration::new(35_344_799);
-        println!("{:?}", RawSeconds::from(five_units));
-        assert!(RawSeconds::from(five_units) == RawSeconds(35_344_799));
+        println!("{:?}", Seconds::from(five_units));
+        assert!(Seconds::from(five_units) == Seconds(35_344_799));
+    }
+
+    #[test]
+    fn test_duration_as_secs() {
+        assert!(Duration::new(35_344_799).as_secs() == 35_344_799);
+        assert!(Duration::ZERO.as_secs() == 0);
+
+        let mut huge = Duration::new(0);
+        huge.years.amount = usize::MAX;
+        assert!(huge.as_secs() == u64::MAX);
+    }
+
+    #[test]
+    fn test_duration_eq_ord_hash() {
+        use std::collections::HashSet;
+
+        let mut denormalized = Duration::ZERO;
+        denormalized.minutes.amount = 75;
+        assert!(denormalized == Duration::new(4500));
+        assert!(Duration::new(4499) < denormalized && denormalized < Duration::new(4501));
+
+        assert!(denormalized.eq_total(&Duration::new(4500)));
+        assert!(!denormalized.eq_components(&Duration::new(4500)));
+        assert!(Duration::new(4500).eq_components(&Duration::new(4500)));
+
+        let set: HashSet<Duration> = vec![denormalized, Duration::new(4500)].into_iter().collect();
+        assert!(set.len() == 1);
+
+        let mut sorted = vec![Duration::new(3), Duration::new(1), Duration::new(2)];
+        sorted.sort();
+        assert!(sorted == [Duration::new(1), Duration::new(2), Duration::new(3)]);
+    }
+
+    #[test]
+    fn test_duration_ranges() {
+        let d = Duration::new(3600);
+        assert!(d.is_between(Duration::new(60), Duration::new(3600)));
+        assert!(!d.is_between(Duration::new(3601), Duration::new(7200)));
+        assert!(!(Duration::new(60)..Duration::new(3600)).contains(&d));
+        assert!((Duration::new(60)..=Duration::new(3600)).contains(&d));
+        assert!((Duration::new(60)..).contains(&d));
+    }
+
+    #[test]
+    fn test_duration_try_into_std() {
+        let five_units = Duration::new(35_344_799);
+        assert!(five_units.try_into_std() == Ok(StdDuration::from_secs(35_344_799)));
+
+        let mut huge = Duration::new(0);
+        huge.years.amount = usize::MAX;
+        assert!(huge.try_into_std() == Err(Error::Overflow));
+    }
+
+    #[test]
+    fn test_duration_try_from_std() {
+        let d = Duration::try_from_std(StdDuration::new(7199, 500)).unwrap();
+        assert!(format!("{}", d) == "1 hour, 59 minutes and 59 seconds.");
+    }
+
+    #[test]
+    fn test_duration_to_annotated() {
+        let annotated = Duration::new(5400).to_annotated();
+        assert!(
+            annotated
+                == Annotated {
+                    text: "1 hour and 30 minutes.".to_string(),
+                    total_seconds: 5400,
+                    components: vec![(TimeUnitKind::Hours, 1), (TimeUnitKind::Minutes, 30)],
+                }
+        );
+        assert!(annotated.text == Duration::new(5400).to_string());
+
+        let zero = Duration::ZERO.to_annotated();
+        assert!(zero.text == "0 seconds." && zero.total_seconds == 0 && zero.components.is_empty());
+    }
+
+    #[test]
+    fn test_duration_to_pairs() {
+        let five_units = Duration::new(35_344_799);
+        assert!(
+            five_units.to_pairs(UnitFilter::NonZero)
+                == vec![("years", 1), ("days", 44), ("hours", 1), ("minutes", 59), ("seconds", 59)]
+        );
+
+        let one_hour = Duration::new(3600);
+        assert!(one_hour.to_pairs(UnitFilter::NonZero) == vec![("hours", 1)]);
+        assert!(
+            one_hour.to_pairs(UnitFilter::All)
+                == vec![("years", 0), ("days", 0), ("hours", 1), ("minutes", 0), ("seconds", 0)]
+        );
+    }
+
+    #[test]
+    fn test_duration_to_map() {
+        let map = Duration::new(7199).to_map(UnitFilter::NonZero);
+        assert!(map.len() == 3);
+        assert!(map["hours"] == 1 && map["minutes"] == 59 && map["seconds"] == 59);
+        assert!(Duration::new(7199).to_map(UnitFilter::All)["days"] == 0);
     }
 }
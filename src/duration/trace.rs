@@ -0,0 +1,25 @@
+use tracing::field::{display, DisplayValue};
+
+use duration::Duration;
+
+impl Duration {
+    /// Wraps `self` for recording as a [`tracing`](https://docs.rs/tracing) field, rendered with
+    /// the prose [`Display`](struct.Duration.html#impl-Display) by fmt subscribers, ex)
+    /// `info!(elapsed = d.as_field(), "done")`. Also usable with `Span::record`.
+    ///
+    /// `tracing::Value` is sealed, which is why `Duration` cannot implement it directly.
+    pub fn as_field(&self) -> DisplayValue<Duration> {
+        display(*self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use duration::Duration;
+
+    #[test]
+    fn test_as_field() {
+        let field = Duration::new(7199).as_field();
+        assert!(format!("{:?}", field) == "1 hour, 59 minutes and 59 seconds.");
+    }
+}
@@ -0,0 +1,72 @@
+const ONES: [&str; 20] = [
+    "zero", "one", "two", "three", "four", "five", "six", "seven", "eight", "nine", "ten",
+    "eleven", "twelve", "thirteen", "fourteen", "fifteen", "sixteen", "seventeen", "eighteen",
+    "nineteen",
+];
+
+const TENS: [&str; 10] = [
+    "", "", "twenty", "thirty", "forty", "fifty", "sixty", "seventy", "eighty", "ninety",
+];
+
+const SCALES: [&str; 7] = [
+    "", "thousand", "million", "billion", "trillion", "quadrillion", "quintillion",
+];
+
+/// Spells `n` below 1000 into `words`, ex) "three hundred forty two".
+fn push_hundreds(n: usize, words: &mut Vec<&'static str>) {
+    if n >= 100 {
+        words.push(ONES[n / 100]);
+        words.push("hundred");
+    }
+    let n = n % 100;
+    let (tens, ones) = (n / 10, n % 10);
+    if n >= 20 {
+        words.push(TENS[tens]);
+        if ones > 0 {
+            words.push(ONES[ones]);
+        }
+    } else if n > 0 {
+        words.push(ONES[n]);
+    }
+}
+
+/// Spells `n` out in English words separated by spaces only, without hyphens or commas that
+/// screen readers may voice, ex) 1234 -> "one thousand two hundred thirty four".
+pub(crate) fn spell(n: usize) -> String {
+    if n == 0 {
+        return ONES[0].to_string();
+    }
+    let mut groups = Vec::new();
+    let mut rest = n;
+    while rest > 0 {
+        groups.push(rest % 1000);
+        rest /= 1000;
+    }
+    let mut words = Vec::new();
+    for (scale, &group) in groups.iter().enumerate().rev() {
+        if group > 0 {
+            push_hundreds(group, &mut words);
+            if scale > 0 {
+                words.push(SCALES[scale]);
+            }
+        }
+    }
+    words.join(" ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::spell;
+
+    #[test]
+    fn test_spell() {
+        assert!(spell(0) == "zero");
+        assert!(spell(7) == "seven");
+        assert!(spell(19) == "nineteen");
+        assert!(spell(59) == "fifty nine");
+        assert!(spell(100) == "one hundred");
+        assert!(spell(1234) == "one thousand two hundred thirty four");
+        assert!(spell(2_000_017) == "two million seventeen");
+        assert!(spell(u64::MAX as usize).starts_with("eighteen quintillion"));
+    }
+}
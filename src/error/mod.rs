@@ -0,0 +1,40 @@
+use std::error;
+use std::fmt;
+
+/// Errors produced when a [`Duration`](../duration/struct.Duration.html) cannot be built from, or
+/// converted into, another representation.
+#[derive(PartialEq, Clone, Copy, Debug)]
+pub enum Error {
+    /// The total amount of seconds does not fit in the target representation.
+    Overflow,
+    /// The name of a time unit was not recognized.
+    UnknownUnit,
+    /// The input does not follow the expected format.
+    InvalidFormat,
+    /// The input is longer, has more components or larger amounts than the parser's
+    /// [`Limits`](../parse/struct.Limits.html) allow.
+    LimitsExceeded,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        f.write_str(match *self {
+            Error::Overflow => "duration overflows the target representation",
+            Error::UnknownUnit => "unknown time unit",
+            Error::InvalidFormat => "invalid duration format",
+            Error::LimitsExceeded => "duration input exceeds the parser's limits",
+        })
+    }
+}
+
+impl error::Error for Error {}
+
+#[cfg(test)]
+mod tests {
+    use error::Error;
+
+    #[test]
+    fn test_error_display() {
+        assert!(format!("{}", Error::Overflow) == "duration overflows the target representation");
+    }
+}
@@ -0,0 +1,169 @@
+//! C-compatible bindings, behind the `ffi` feature. The types and functions are laid out for
+//! header generation with cbindgen.
+
+use std::ffi::CStr;
+use std::os::raw::{c_char, c_int};
+use std::ptr;
+
+use duration::Duration;
+use error::Error;
+
+pub const DURATION_OK: c_int = 0;
+pub const DURATION_ERR_NULL: c_int = -1;
+pub const DURATION_ERR_OVERFLOW: c_int = 1;
+pub const DURATION_ERR_UNKNOWN_UNIT: c_int = 2;
+pub const DURATION_ERR_INVALID_FORMAT: c_int = 3;
+pub const DURATION_ERR_LIMITS_EXCEEDED: c_int = 4;
+
+/// The units of a [`Duration`](../duration/struct.Duration.html), passed by value across the FFI
+/// boundary.
+#[repr(C)]
+#[derive(PartialEq, Clone, Copy, Debug)]
+pub struct DurationRepr {
+    pub years: u64,
+    pub days: u64,
+    pub hours: u64,
+    pub minutes: u64,
+    pub seconds: u64,
+}
+
+impl From<Duration> for DurationRepr {
+    fn from(d: Duration) -> Self {
+        DurationRepr {
+            years: d.years.amount as u64,
+            days: d.days.amount as u64,
+            hours: d.hours.amount as u64,
+            minutes: d.minutes.amount as u64,
+            seconds: d.seconds.amount as u64,
+        }
+    }
+}
+
+impl From<DurationRepr> for Duration {
+    fn from(repr: DurationRepr) -> Self {
+        let mut d = Duration::new(0);
+        d.years.amount = repr.years as usize;
+        d.days.amount = repr.days as usize;
+        d.hours.amount = repr.hours as usize;
+        d.minutes.amount = repr.minutes as usize;
+        d.seconds.amount = repr.seconds as usize;
+        d
+    }
+}
+
+fn error_code(e: Error) -> c_int {
+    match e {
+        Error::Overflow => DURATION_ERR_OVERFLOW,
+        Error::UnknownUnit => DURATION_ERR_UNKNOWN_UNIT,
+        Error::InvalidFormat => DURATION_ERR_INVALID_FORMAT,
+        Error::LimitsExceeded => DURATION_ERR_LIMITS_EXCEEDED,
+    }
+}
+
+/// Copies `s` into `buf` with a terminating NUL when it fits in `len` bytes, in the manner of
+/// `snprintf`, and returns the length of `s`.
+unsafe fn write_buf(s: &str, buf: *mut c_char, len: usize) -> isize {
+    if !buf.is_null() && s.len() < len {
+        ptr::copy_nonoverlapping(s.as_ptr() as *const c_char, buf, s.len());
+        *buf.add(s.len()) = 0;
+    }
+    s.len() as isize
+}
+
+/// Decomposes `seconds` into its units.
+#[no_mangle]
+pub extern "C" fn duration_from_seconds(seconds: u64) -> DurationRepr {
+    Duration::new(seconds as usize).into()
+}
+
+/// Writes the compact form of `d`, ex) "1h 59m 59s", into `buf` and returns its length without
+/// the terminating NUL. Nothing is written when `buf` is null or `len` is too small, so callers
+/// can size a buffer by passing a null `buf` first.
+///
+/// # Safety
+///
+/// `buf` must be null or valid for writes of `len` bytes.
+#[no_mangle]
+pub unsafe extern "C" fn duration_format_compact(
+    d: DurationRepr,
+    buf: *mut c_char,
+    len: usize,
+) -> isize {
+    write_buf(&Duration::from(d).format_compact(), buf, len)
+}
+
+/// Like [`duration_format_compact`](fn.duration_format_compact.html), but writes the prose form,
+/// ex) "1 hour, 59 minutes and 59 seconds.".
+///
+/// # Safety
+///
+/// `buf` must be null or valid for writes of `len` bytes.
+#[no_mangle]
+pub unsafe extern "C" fn duration_format(d: DurationRepr, buf: *mut c_char, len: usize) -> isize {
+    write_buf(&Duration::from(d).to_string(), buf, len)
+}
+
+/// Parses the NUL-terminated UTF-8 string `s` into `out`, returning `DURATION_OK` or one of the
+/// `DURATION_ERR_*` codes.
+///
+/// # Safety
+///
+/// `s` must be null or point to a NUL-terminated string, and `out` must be null or valid for
+/// writes.
+#[no_mangle]
+pub unsafe extern "C" fn duration_parse(s: *const c_char, out: *mut DurationRepr) -> c_int {
+    if s.is_null() || out.is_null() {
+        return DURATION_ERR_NULL;
+    }
+    let parsed = CStr::from_ptr(s)
+        .to_str()
+        .map_err(|_| Error::InvalidFormat)
+        .and_then(|s| s.parse::<Duration>());
+    match parsed {
+        Ok(d) => {
+            *out = d.into();
+            DURATION_OK
+        }
+        Err(e) => error_code(e),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::ffi::{CStr, CString};
+    use std::os::raw::c_char;
+    use std::ptr;
+
+    use ffi::*;
+
+    #[test]
+    fn test_ffi_format() {
+        let d = duration_from_seconds(7199);
+        assert!(d.hours == 1 && d.minutes == 59 && d.seconds == 59);
+
+        let mut buf = [0 as c_char; 16];
+        unsafe {
+            assert!(duration_format_compact(d, ptr::null_mut(), 0) == 10);
+            assert!(duration_format_compact(d, buf.as_mut_ptr(), buf.len()) == 10);
+            assert!(CStr::from_ptr(buf.as_ptr()).to_str() == Ok("1h 59m 59s"));
+
+            let mut small = [0 as c_char; 4];
+            assert!(duration_format(d, small.as_mut_ptr(), small.len()) == 34);
+            assert!(small[0] == 0);
+        }
+    }
+
+    #[test]
+    fn test_ffi_parse() {
+        let mut out = duration_from_seconds(0);
+        let s = CString::new("1h 59m 59s").unwrap();
+        unsafe {
+            assert!(duration_parse(s.as_ptr(), &mut out) == DURATION_OK);
+            assert!(out == duration_from_seconds(7199));
+
+            let s = CString::new("soon").unwrap();
+            assert!(duration_parse(s.as_ptr(), &mut out) == DURATION_ERR_INVALID_FORMAT);
+            assert!(duration_parse(ptr::null(), &mut out) == DURATION_ERR_NULL);
+        }
+    }
+}
@@ -0,0 +1,147 @@
+//! Reading and writing the durations carried by HTTP header values, ex) `Retry-After: 120`,
+//! `Cache-Control: max-age=3600` or `Keep-Alive: timeout=5`.
+
+use std::time::SystemTime;
+
+use business::Date;
+use calendar::unix_secs;
+use duration::Duration;
+use error::Error;
+
+const MONTHS: [&str; 12] = [
+    "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+];
+
+/// Reads delay-seconds, a non-negative decimal integer. Too large a delay is an overflow.
+fn parse_seconds(s: &str) -> Result<Duration, Error> {
+    if s.is_empty() || !s.chars().all(|c| c.is_ascii_digit()) {
+        return Err(Error::InvalidFormat);
+    }
+    s.parse().map(Duration::new).map_err(|_| Error::Overflow)
+}
+
+/// Reads an IMF-fixdate as Unix epoch seconds, ex) "Sun, 06 Nov 1994 08:49:37 GMT". The obsolete
+/// RFC 850 and asctime formats are not accepted.
+fn parse_http_date(s: &str) -> Result<i64, Error> {
+    let fields = s.split(' ').collect::<Vec<&str>>();
+    let (day, month, year, time) = match fields[..] {
+        [weekday, day, month, year, time, "GMT"] if weekday.len() == 4 && weekday.ends_with(',') => {
+            (day, month, year, time)
+        }
+        _ => return Err(Error::InvalidFormat),
+    };
+    let number = |s: &str, len: usize| -> Result<i64, Error> {
+        if s.len() != len || !s.chars().all(|c| c.is_ascii_digit()) {
+            return Err(Error::InvalidFormat);
+        }
+        Ok(s.parse().unwrap())
+    };
+    let month = MONTHS.iter().position(|m| *m == month).ok_or(Error::InvalidFormat)? as u32 + 1;
+    let clock = time.split(':').collect::<Vec<&str>>();
+    if clock.len() != 3 {
+        return Err(Error::InvalidFormat);
+    }
+    let (h, m, sec) = (number(clock[0], 2)?, number(clock[1], 2)?, number(clock[2], 2)?);
+    let (day, year) = (number(day, 2)?, number(year, 4)?);
+    let month_days = Duration::of_month(year as i32, month)?.total_seconds() as i64 / 86_400;
+    if h > 23 || m > 59 || sec > 60 || !(1..=month_days).contains(&day) {
+        return Err(Error::InvalidFormat);
+    }
+    let days = Date::new(year as i32, month, day as u32).days_since_epoch();
+    Ok(days * 86_400 + h * 3600 + m * 60 + sec)
+}
+
+/// Reads the value of a `Retry-After` header, either delay-seconds, ex) "120", or an HTTP-date,
+/// ex) "Wed, 21 Oct 2015 07:28:00 GMT", which is taken relative to `now`. Dates already passed
+/// yield zero.
+pub fn parse_retry_after(value: &str, now: SystemTime) -> Result<Duration, Error> {
+    let value = value.trim();
+    if value.starts_with(|c: char| c.is_ascii_digit()) {
+        return parse_seconds(value);
+    }
+    let secs = parse_http_date(value)? - unix_secs(now);
+    Ok(Duration::new(secs.max(0) as usize))
+}
+
+/// Finds the directive `name` in a comma separated list of `name=value` parameters, ignoring
+/// case and whitespace, and reads its value as seconds. Quoted values are accepted.
+fn find_seconds(value: &str, name: &str) -> Result<Option<Duration>, Error> {
+    for directive in value.split(',') {
+        let mut parts = directive.splitn(2, '=');
+        if parts.next().map(str::trim).is_some_and(|key| key.eq_ignore_ascii_case(name)) {
+            let secs = parts.next().ok_or(Error::InvalidFormat)?.trim().trim_matches('"');
+            return parse_seconds(secs).map(Some);
+        }
+    }
+    Ok(None)
+}
+
+/// Reads the `max-age` directive of a `Cache-Control` header, ex) "public, max-age=3600".
+/// Returns `None` without one.
+pub fn parse_max_age(cache_control: &str) -> Result<Option<Duration>, Error> {
+    find_seconds(cache_control, "max-age")
+}
+
+/// Reads the `timeout` parameter of a `Keep-Alive` header, ex) "timeout=5, max=1000". Returns
+/// `None` without one.
+pub fn parse_keep_alive_timeout(keep_alive: &str) -> Result<Option<Duration>, Error> {
+    find_seconds(keep_alive, "timeout")
+}
+
+impl Duration {
+    /// Formats `self` as the delay-seconds of a `Retry-After` header, ex) "120".
+    pub fn to_retry_after(&self) -> String {
+        self.total_seconds().to_string()
+    }
+
+    /// Formats `self` as a `Cache-Control` directive, ex) "max-age=3600".
+    pub fn to_max_age(&self) -> String {
+        format!("max-age={}", self.total_seconds())
+    }
+
+    /// Formats `self` as a `Keep-Alive` parameter, ex) "timeout=5".
+    pub fn to_keep_alive(&self) -> String {
+        format!("timeout={}", self.total_seconds())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::{Duration as StdDuration, UNIX_EPOCH};
+
+    use duration::Duration;
+    use error::Error;
+    use http::{parse_keep_alive_timeout, parse_max_age, parse_retry_after};
+
+    #[test]
+    fn test_parse_retry_after() {
+        let now = UNIX_EPOCH + StdDuration::from_secs(1_445_412_400);
+        assert!(parse_retry_after("120", now) == Ok(Duration::new(120)));
+        assert!(parse_retry_after("Wed, 21 Oct 2015 07:28:00 GMT", now) == Ok(Duration::new(80)));
+        assert!(parse_retry_after("Tue, 20 Oct 2015 07:28:00 GMT", now) == Ok(Duration::new(0)));
+        assert!(parse_retry_after("-5", now) == Err(Error::InvalidFormat));
+        assert!(parse_retry_after("Wed, 21 Oct 2015 07:28:00 PST", now) == Err(Error::InvalidFormat));
+        assert!(parse_retry_after("Wed, 21 Foo 2015 07:28:00 GMT", now) == Err(Error::InvalidFormat));
+        assert!(parse_retry_after("Wed, 31 Feb 2015 07:28:00 GMT", now) == Err(Error::InvalidFormat));
+        assert!(parse_retry_after("Wed, 29 Feb 2012 00:00:00 GMT", now) == Ok(Duration::ZERO));
+        assert!(parse_retry_after("99999999999999999999999", now) == Err(Error::Overflow));
+    }
+
+    #[test]
+    fn test_parse_max_age_and_keep_alive() {
+        assert!(parse_max_age("public, max-age=3600") == Ok(Some(Duration::new(3600))));
+        assert!(parse_max_age("Max-Age=\"60\", no-transform") == Ok(Some(Duration::new(60))));
+        assert!(parse_max_age("no-store") == Ok(None));
+        assert!(parse_max_age("max-age=soon") == Err(Error::InvalidFormat));
+        assert!(parse_keep_alive_timeout("timeout=5, max=1000") == Ok(Some(Duration::new(5))));
+        assert!(parse_keep_alive_timeout("max=1000") == Ok(None));
+    }
+
+    #[test]
+    fn test_format_headers() {
+        let d = Duration::new(3600);
+        assert!(d.to_retry_after() == "3600");
+        assert!(d.to_max_age() == "max-age=3600");
+        assert!(Duration::new(5).to_keep_alive() == "timeout=5");
+    }
+}
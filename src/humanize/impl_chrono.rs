@@ -0,0 +1,27 @@
+use chrono::TimeDelta;
+
+use humanize::{saturating_secs, HumanizeExt};
+
+impl HumanizeExt for TimeDelta {
+    fn humanize(&self) -> String {
+        saturating_secs(self.num_seconds()).to_string()
+    }
+
+    fn humanize_compact(&self) -> String {
+        saturating_secs(self.num_seconds()).format_compact()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::TimeDelta;
+
+    use humanize::HumanizeExt;
+
+    #[test]
+    fn test_humanize_chrono() {
+        assert!(TimeDelta::minutes(90).humanize() == "1 hour and 30 minutes.");
+        assert!(TimeDelta::minutes(90).humanize_compact() == "1h 30m");
+        assert!(TimeDelta::minutes(-90).humanize_compact() == "0s");
+    }
+}
@@ -0,0 +1,23 @@
+use humanize::{saturating_secs, HumanizeExt};
+
+impl HumanizeExt for time::Duration {
+    fn humanize(&self) -> String {
+        saturating_secs(self.whole_seconds()).to_string()
+    }
+
+    fn humanize_compact(&self) -> String {
+        saturating_secs(self.whole_seconds()).format_compact()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use humanize::HumanizeExt;
+
+    #[test]
+    fn test_humanize_time() {
+        assert!(time::Duration::minutes(90).humanize() == "1 hour and 30 minutes.");
+        assert!(time::Duration::seconds(3661).humanize_compact() == "1h 1m 1s");
+        assert!(time::Duration::minutes(-90).humanize_compact() == "0s");
+    }
+}
@@ -0,0 +1,70 @@
+use std::time::Duration as StdDuration;
+
+use duration::Duration;
+
+#[cfg(feature = "chrono")]
+mod impl_chrono;
+#[cfg(feature = "time")]
+mod impl_time;
+
+/// Humanizes durations of other types with this crate's formatting, ex)
+/// `std_duration.humanize()`. Durations beyond the range of a
+/// [`Duration`](../duration/struct.Duration.html) saturate, negative ones to zero.
+pub trait HumanizeExt {
+    /// Formats `self` as [`Duration`](../duration/struct.Duration.html)'s prose `Display`.
+    fn humanize(&self) -> String;
+
+    /// Formats `self` as
+    /// [`Duration::format_compact`](../duration/struct.Duration.html#method.format_compact),
+    /// ex) "1h 59m 59s".
+    fn humanize_compact(&self) -> String;
+}
+
+/// Whole seconds of a signed duration, saturating negative ones to zero.
+#[cfg(any(feature = "chrono", feature = "time"))]
+fn saturating_secs(secs: i64) -> Duration {
+    use std::convert::TryFrom;
+
+    let secs = u64::try_from(secs).unwrap_or(0);
+    Duration::new(usize::try_from(secs).unwrap_or(usize::MAX))
+}
+
+impl HumanizeExt for Duration {
+    fn humanize(&self) -> String {
+        self.to_string()
+    }
+
+    fn humanize_compact(&self) -> String {
+        self.format_compact()
+    }
+}
+
+impl HumanizeExt for StdDuration {
+    fn humanize(&self) -> String {
+        Duration::try_from_std(*self)
+            .unwrap_or_else(|_| Duration::new(usize::MAX))
+            .to_string()
+    }
+
+    fn humanize_compact(&self) -> String {
+        Duration::try_from_std(*self)
+            .unwrap_or_else(|_| Duration::new(usize::MAX))
+            .format_compact()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration as StdDuration;
+
+    use duration::Duration;
+    use humanize::HumanizeExt;
+
+    #[test]
+    fn test_humanize() {
+        assert!(StdDuration::from_millis(7_199_999).humanize() == "1 hour, 59 minutes and 59 seconds.");
+        assert!(StdDuration::from_millis(7_199_999).humanize_compact() == "1h 59m 59s");
+        assert!(Duration::new(60).humanize() == "1 minute.");
+        assert!(Duration::new(60).humanize_compact() == "1m");
+    }
+}
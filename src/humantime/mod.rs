@@ -0,0 +1,123 @@
+//! Compatibility with the duration format of the `humantime` crate, ex) "1year 2months 3days 4h",
+//! so that configurations written for it keep working. That format counts years of 365.25 days
+//! and months of 30.44 days, unlike the 365 day years of
+//! [`Duration`](../duration/struct.Duration.html)'s own formats.
+
+use std::convert::TryFrom;
+
+use duration::Duration;
+use error::Error;
+use parse::NANOS_PER_SEC;
+
+const YEAR: usize = 31_557_600;
+const MONTH: usize = 2_630_016;
+const DAY: usize = 86_400;
+
+/// The length of a humantime unit in nanoseconds.
+fn unit_nanos(unit: &str) -> Option<u128> {
+    let secs = match unit {
+        "nanos" | "nsec" | "ns" => return Some(1),
+        "usec" | "us" | "µs" => return Some(1_000),
+        "millis" | "msec" | "ms" => return Some(1_000_000),
+        "seconds" | "second" | "secs" | "sec" | "s" => 1,
+        "minutes" | "minute" | "min" | "mins" | "m" => 60,
+        "hours" | "hour" | "hr" | "hrs" | "h" => 3600,
+        "days" | "day" | "d" => DAY,
+        "weeks" | "week" | "w" => 7 * DAY,
+        "months" | "month" | "M" => MONTH,
+        "years" | "year" | "y" => YEAR,
+        _ => return None,
+    };
+    Some(secs as u128 * NANOS_PER_SEC)
+}
+
+/// Parses a duration as `humantime::parse_duration` does, ex) "2h 37min", "1year 2months" or
+/// "15days 2min 2s". Amounts are whole numbers, optionally separated from their unit by spaces.
+/// What remains below a whole second is truncated.
+pub fn parse_duration(s: &str) -> Result<Duration, Error> {
+    let mut nanos: u128 = 0;
+    let mut rest = s.trim_start();
+    if rest.is_empty() {
+        return Err(Error::InvalidFormat);
+    }
+    while !rest.is_empty() {
+        let digits = rest.find(|c: char| !c.is_ascii_digit()).unwrap_or(rest.len());
+        if digits == 0 {
+            return Err(Error::InvalidFormat);
+        }
+        let amount: u128 = rest[..digits].parse().map_err(|_| Error::Overflow)?;
+        rest = rest[digits..].trim_start();
+
+        let unit_len = rest.find(|c: char| !c.is_alphabetic()).unwrap_or(rest.len());
+        if unit_len == 0 {
+            return Err(Error::InvalidFormat);
+        }
+        let unit = unit_nanos(&rest[..unit_len]).ok_or(Error::UnknownUnit)?;
+        nanos = amount
+            .checked_mul(unit)
+            .and_then(|n| nanos.checked_add(n))
+            .ok_or(Error::Overflow)?;
+        rest = rest[unit_len..].trim_start();
+    }
+    usize::try_from(nanos / NANOS_PER_SEC)
+        .map(Duration::new)
+        .map_err(|_| Error::Overflow)
+}
+
+impl Duration {
+    /// Formats `self` as `humantime::format_duration` does, ex) "1year 2months 3days 4h 5m 6s".
+    /// Zero formats as "0s".
+    pub fn format_humantime(&self) -> String {
+        let secs = self.total_seconds();
+        let day_secs = secs % YEAR % MONTH % DAY;
+        let units = [
+            (secs / YEAR, "year"),
+            (secs % YEAR / MONTH, "month"),
+            (secs % YEAR % MONTH / DAY, "day"),
+            (day_secs / 3600, "h"),
+            (day_secs % 3600 / 60, "m"),
+            (day_secs % 60, "s"),
+        ];
+
+        let mut parts = Vec::new();
+        for &(amount, name) in units.iter().filter(|&&(amount, _)| amount > 0) {
+            let plural = amount > 1 && name.len() > 1;
+            parts.push(format!("{}{}{}", amount, name, if plural { "s" } else { "" }));
+        }
+        if parts.is_empty() {
+            "0s".to_string()
+        } else {
+            parts.join(" ")
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use duration::Duration;
+    use error::Error;
+    use humantime::parse_duration;
+
+    #[test]
+    fn test_parse_humantime() {
+        assert!(parse_duration("2h 37min") == Ok(Duration::new(9420)));
+        assert!(parse_duration("1year 2months") == Ok(Duration::new(31_557_600 + 2 * 2_630_016)));
+        assert!(parse_duration("15days 2min 2s") == Ok(Duration::new(15 * 86_400 + 122)));
+        assert!(parse_duration("2 weeks 1500ms") == Ok(Duration::new(14 * 86_400 + 1)));
+        assert!(parse_duration("1M") == Ok(Duration::new(2_630_016)));
+        assert!(parse_duration("") == Err(Error::InvalidFormat));
+        assert!(parse_duration("1.5h") == Err(Error::InvalidFormat));
+        assert!(parse_duration("2 fortnights") == Err(Error::UnknownUnit));
+    }
+
+    #[test]
+    fn test_format_humantime() {
+        let d = Duration::new(31_557_600 + 2 * 2_630_016 + 3 * 86_400 + 4 * 3600 + 5 * 60 + 6);
+        assert!(d.format_humantime() == "1year 2months 3days 4h 5m 6s");
+        assert!(Duration::new(38_015_106).format_humantime() == "1year 2months 13days 20h 37m 54s");
+        assert!(Duration::new(63_115_200).format_humantime() == "2years");
+        assert!(Duration::new(86_400 + 1).format_humantime() == "1day 1s");
+        assert!(Duration::ZERO.format_humantime() == "0s");
+        assert!(parse_duration(&d.format_humantime()) == Ok(d));
+    }
+}
@@ -0,0 +1,111 @@
+//! Spans of wall-clock time and how they relate to one another, ex) whether two meetings
+//! overlap and by how much.
+
+use std::cmp;
+use std::fmt;
+use std::time::SystemTime;
+
+use calendar::unix_secs;
+use duration::Duration;
+
+/// A span of `duration` from `start`, compared in whole seconds.
+#[derive(PartialEq, Clone, Copy)]
+pub struct Interval {
+    pub start: SystemTime,
+    pub duration: Duration,
+}
+
+/// How two intervals relate, see [`Interval::relation`](struct.Interval.html#method.relation).
+#[derive(PartialEq, Clone, Copy)]
+pub enum Relation {
+    /// Both share the duration, ex) "overlapping by 20 minutes".
+    Overlapping(Duration),
+    /// One ends as the other starts, ex) "back to back".
+    Adjacent,
+    /// The duration lies between both, ex) "15 minutes apart".
+    Apart(Duration),
+}
+
+impl Interval {
+    pub fn new(start: SystemTime, duration: Duration) -> Self {
+        Interval { start, duration }
+    }
+
+    fn bounds(&self) -> (i128, i128) {
+        let start = unix_secs(self.start) as i128;
+        (start, start + self.duration.total_seconds() as i128)
+    }
+
+    /// The time both intervals share, `None` when they do not overlap.
+    pub fn overlap(&self, other: &Interval) -> Option<Duration> {
+        match self.relation(other) {
+            Relation::Overlapping(d) => Some(d),
+            _ => None,
+        }
+    }
+
+    /// The time between both intervals, zero when one ends as the other starts and `None`
+    /// when they overlap.
+    pub fn gap(&self, other: &Interval) -> Option<Duration> {
+        match self.relation(other) {
+            Relation::Overlapping(_) => None,
+            Relation::Adjacent => Some(Duration::ZERO),
+            Relation::Apart(d) => Some(d),
+        }
+    }
+
+    pub fn relation(&self, other: &Interval) -> Relation {
+        let (start, end) = self.bounds();
+        let (other_start, other_end) = other.bounds();
+        let shared = cmp::min(end, other_end) - cmp::max(start, other_start);
+        let secs = Duration::new(shared.unsigned_abs() as usize);
+        match shared.cmp(&0) {
+            cmp::Ordering::Greater => Relation::Overlapping(secs),
+            cmp::Ordering::Equal => Relation::Adjacent,
+            cmp::Ordering::Less => Relation::Apart(secs),
+        }
+    }
+}
+
+impl fmt::Display for Relation {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let prose = |d: &Duration| d.to_string().trim_end_matches('.').to_string();
+        match self {
+            Relation::Overlapping(d) => write!(f, "overlapping by {}", prose(d)),
+            Relation::Adjacent => f.write_str("back to back"),
+            Relation::Apart(d) => write!(f, "{} apart", prose(d)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::{Duration as StdDuration, UNIX_EPOCH};
+
+    use duration::Duration;
+    use interval::{Interval, Relation};
+
+    #[test]
+    fn test_overlap_and_gap() {
+        let at = |minutes: u64| UNIX_EPOCH + StdDuration::from_secs(1_700_000_000 + minutes * 60);
+        let standup = Interval::new(at(0), Duration::new(3600));
+        let review = Interval::new(at(40), Duration::new(1800));
+        assert!(standup.overlap(&review) == Some(Duration::new(1200)));
+        assert!(review.overlap(&standup) == Some(Duration::new(1200)));
+        assert!(standup.gap(&review).is_none());
+        assert!(standup.relation(&review).to_string() == "overlapping by 20 minutes");
+
+        let lunch = Interval::new(at(60), Duration::new(3600));
+        assert!(standup.relation(&lunch) == Relation::Adjacent);
+        assert!(standup.gap(&lunch) == Some(Duration::ZERO));
+        assert!(standup.relation(&lunch).to_string() == "back to back");
+
+        let retro = Interval::new(at(135), Duration::new(3600));
+        assert!(standup.overlap(&retro).is_none());
+        assert!(retro.gap(&standup) == Some(Duration::new(4500)));
+        assert!(standup.relation(&retro).to_string() == "1 hour and 15 minutes apart");
+
+        let inner = Interval::new(at(10), Duration::new(600));
+        assert!(standup.overlap(&inner) == Some(Duration::new(600)));
+    }
+}
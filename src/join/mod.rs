@@ -0,0 +1,86 @@
+use std::fmt::{self, Write};
+
+/// How [`join_human`](fn.join_human.html) joins items.
+#[derive(PartialEq, Clone, Copy, Debug)]
+pub struct JoinOptions<'a> {
+    /// Placed between items other than the last two, ex) ", " or the Arabic "، ".
+    pub separator: &'a str,
+    /// The word joining the last two items, ex) "and" or "or".
+    pub conjunction: &'a str,
+    /// Whether a comma precedes the conjunction when there are three or more items, ex)
+    /// "a, b, and c".
+    pub serial_comma: bool,
+    /// Appended after the last item, ex) ".".
+    pub terminator: &'a str,
+}
+
+impl<'a> Default for JoinOptions<'a> {
+    /// Joins the way [`Duration`](../duration/struct.Duration.html)'s `Display` joins its units,
+    /// ex) "a, b and c.".
+    fn default() -> Self {
+        JoinOptions {
+            separator: ", ",
+            conjunction: "and",
+            serial_comma: false,
+            terminator: ".",
+        }
+    }
+}
+
+/// Joins `items` into a natural language list, ex) "a, b and c." with the default
+/// [`JoinOptions`](struct.JoinOptions.html). Nothing is written for no items, not even the
+/// terminator.
+pub fn join_human<I>(items: I, options: &JoinOptions) -> String
+where
+    I: IntoIterator,
+    I::Item: fmt::Display,
+{
+    let items = items.into_iter().collect::<Vec<I::Item>>();
+    let mut s = String::new();
+    for (i, item) in items.iter().enumerate() {
+        if i > 0 && i + 1 < items.len() {
+            s.push_str(options.separator);
+        } else if i > 0 {
+            if options.serial_comma && items.len() > 2 {
+                s.push_str(options.separator.trim_end());
+            }
+            s.push(' ');
+            s.push_str(options.conjunction);
+            s.push(' ');
+        }
+        let _ = write!(s, "{}", item);
+    }
+    if !items.is_empty() {
+        s.push_str(options.terminator);
+    }
+    s
+}
+
+#[cfg(test)]
+mod tests {
+    use join::{join_human, JoinOptions};
+
+    #[test]
+    fn test_join_human() {
+        let options = JoinOptions::default();
+        assert!(join_human(Vec::<&str>::new(), &options).is_empty());
+        assert!(join_human(vec!["a"], &options) == "a.");
+        assert!(join_human(vec!["a", "b"], &options) == "a and b.");
+        assert!(join_human(vec!["a", "b", "c"], &options) == "a, b and c.");
+    }
+
+    #[test]
+    fn test_join_human_options() {
+        let options = JoinOptions {
+            separator: ", ",
+            conjunction: "or",
+            serial_comma: true,
+            terminator: "",
+        };
+        assert!(join_human([1, 2], &options) == "1 or 2");
+        assert!(join_human([1, 2, 3], &options) == "1, 2, or 3");
+
+        let options = JoinOptions { separator: "، ", conjunction: "و", ..options };
+        assert!(join_human([1, 2, 3], &options) == "1، 2، و 3");
+    }
+}
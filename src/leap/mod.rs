@@ -0,0 +1,109 @@
+use std::time::SystemTime;
+
+use calendar::unix_secs;
+use duration::Duration;
+
+/// Unix timestamps of the midnights UTC preceded by a positive leap second, 1972 through 2016.
+const BUNDLED: [i64; 27] = [
+    78_796_800,    // 1972-07-01
+    94_694_400,    // 1973-01-01
+    126_230_400,   // 1974-01-01
+    157_766_400,   // 1975-01-01
+    189_302_400,   // 1976-01-01
+    220_924_800,   // 1977-01-01
+    252_460_800,   // 1978-01-01
+    283_996_800,   // 1979-01-01
+    315_532_800,   // 1980-01-01
+    362_793_600,   // 1981-07-01
+    394_329_600,   // 1982-07-01
+    425_865_600,   // 1983-07-01
+    489_024_000,   // 1985-07-01
+    567_993_600,   // 1988-01-01
+    631_152_000,   // 1990-01-01
+    662_688_000,   // 1991-01-01
+    709_948_800,   // 1992-07-01
+    741_484_800,   // 1993-07-01
+    773_020_800,   // 1994-07-01
+    820_454_400,   // 1996-01-01
+    867_715_200,   // 1997-07-01
+    915_148_800,   // 1999-01-01
+    1_136_073_600, // 2006-01-01
+    1_230_768_000, // 2009-01-01
+    1_341_100_800, // 2012-07-01
+    1_435_708_800, // 2015-07-01
+    1_483_228_800, // 2017-01-01
+];
+
+/// A table of leap seconds. Unix time skips leap seconds, so a wall-clock delta between two
+/// timestamps undercounts the seconds that actually elapsed by the leap seconds in between.
+#[derive(PartialEq, Clone, Debug)]
+pub struct LeapSeconds {
+    /// Sorted Unix timestamps, each preceded by a positive leap second.
+    inserted: Vec<i64>,
+}
+
+impl LeapSeconds {
+    /// The leap seconds announced by the IERS, the latest being inserted at the end of
+    /// 2016-12-31. Use [`new`](struct.LeapSeconds.html#method.new) for a more recent table.
+    pub fn bundled() -> Self {
+        LeapSeconds { inserted: BUNDLED.to_vec() }
+    }
+
+    /// A user-supplied table of Unix timestamps, each preceded by a positive leap second.
+    pub fn new(mut inserted: Vec<i64>) -> Self {
+        inserted.sort_unstable();
+        LeapSeconds { inserted }
+    }
+
+    /// The amount of leap seconds inserted after the Unix timestamp `start`, up to `end`.
+    pub fn count_between(&self, start: i64, end: i64) -> usize {
+        let after = |t: i64| self.inserted.partition_point(|&leap| leap <= t);
+        after(end).saturating_sub(after(start))
+    }
+}
+
+impl Duration {
+    /// The time elapsed from `start` to `end`, or a zero duration if `end` is not after `start`.
+    /// With a leap second table, leap seconds inserted in between are counted, yielding the exact
+    /// amount of elapsed seconds rather than the wall-clock delta.
+    pub fn between_wall_clock(
+        start: SystemTime,
+        end: SystemTime,
+        leap_seconds: Option<&LeapSeconds>,
+    ) -> Self {
+        let (start, end) = (unix_secs(start), unix_secs(end));
+        if end <= start {
+            return Duration::new(0);
+        }
+        let leaps = leap_seconds.map_or(0, |table| table.count_between(start, end));
+        Duration::new((end - start) as usize + leaps)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::{Duration as StdDuration, UNIX_EPOCH};
+
+    use duration::Duration;
+    use leap::LeapSeconds;
+
+    #[test]
+    fn test_count_between() {
+        let table = LeapSeconds::bundled();
+        assert!(table.count_between(0, 1_500_000_000) == 27);
+        assert!(table.count_between(1_483_228_799, 1_483_228_800) == 1);
+        assert!(table.count_between(1_483_228_800, 1_483_228_801) == 0);
+        assert!(table.count_between(1_500_000_000, 0) == 0);
+        assert!(LeapSeconds::new(vec![20, 10]).count_between(0, 15) == 1);
+    }
+
+    #[test]
+    fn test_between_wall_clock() {
+        let start = UNIX_EPOCH + StdDuration::from_secs(1_483_228_000);
+        let end = UNIX_EPOCH + StdDuration::from_secs(1_483_229_000);
+        assert!(Duration::between_wall_clock(start, end, None) == Duration::new(1000));
+        let table = LeapSeconds::bundled();
+        assert!(Duration::between_wall_clock(start, end, Some(&table)) == Duration::new(1001));
+        assert!(Duration::between_wall_clock(end, start, Some(&table)) == Duration::new(0));
+    }
+}
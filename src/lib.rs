@@ -1,2 +1,95 @@
+#[cfg(feature = "async-graphql")]
+extern crate async_graphql;
+#[cfg(feature = "async-std")]
+extern crate async_std;
+#[cfg(feature = "bincode")]
+#[macro_use]
+extern crate bincode;
+#[cfg(feature = "chrono")]
+extern crate chrono;
+// The pyo3 macros expand to `::core` paths, which the 2015 edition resolves from the crate root.
+#[cfg(feature = "pyo3")]
+extern crate core;
+#[cfg(feature = "comfy-table")]
+extern crate comfy_table;
+#[cfg(feature = "criterion")]
+extern crate criterion;
+#[cfg(feature = "derive")]
+extern crate duration_string_derive;
+#[cfg(feature = "icu")]
+extern crate fixed_decimal;
+#[cfg(feature = "fluent")]
+extern crate fluent_bundle;
+#[cfg(feature = "icu")]
+extern crate icu_experimental;
+#[cfg(feature = "icu")]
+extern crate icu_list;
+#[cfg(feature = "icu")]
+extern crate icu_locale_core;
+#[cfg(feature = "jiff")]
+extern crate jiff;
+#[cfg(feature = "juniper")]
+extern crate juniper;
+#[cfg(feature = "pyo3")]
+extern crate pyo3;
+#[cfg(feature = "rayon")]
+extern crate rayon;
+#[cfg(feature = "serde")]
+extern crate serde as serde_crate;
+#[cfg(all(test, feature = "serde"))]
+extern crate serde_json;
+#[cfg(feature = "time")]
+extern crate time;
+#[cfg(any(test, feature = "tokio"))]
+extern crate tokio;
+#[cfg(feature = "tracing")]
+extern crate tracing;
+#[cfg(feature = "unicode-width")]
+extern crate unicode_width;
+
+pub mod batch;
+pub mod binary;
+pub mod business;
+pub mod cache;
+pub mod calendar;
+pub mod clock;
 pub mod duration;
-pub use duration::Duration;
+pub mod error;
+#[cfg(feature = "ffi")]
+pub mod ffi;
+pub mod http;
+pub mod humanize;
+pub mod humantime;
+pub mod interval;
+pub mod join;
+pub mod leap;
+pub mod locale;
+pub mod media;
+pub mod parse;
+pub mod precision;
+pub mod progress;
+#[cfg(feature = "pyo3")]
+pub mod python;
+pub mod rate;
+pub mod relative;
+pub mod runtime;
+#[cfg(feature = "serde")]
+pub mod serde;
+pub mod session;
+pub mod si;
+pub mod signed;
+pub mod stats;
+pub mod table;
+pub mod ticks;
+pub mod timesheet;
+pub mod translate;
+pub mod ttl;
+pub mod units;
+pub use duration::{Duration, FormatOptions, Seconds};
+pub use error::Error;
+pub use humanize::HumanizeExt;
+pub use join::{join_human, JoinOptions};
+pub use signed::SignedDuration;
+
+#[cfg(feature = "derive")]
+pub use duration_string_derive::HumanizeDurations;
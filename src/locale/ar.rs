@@ -0,0 +1,42 @@
+//! Unit names and plural rule of the CLDR `ar` locale, from the long unit patterns of
+//! `units.json` in cldr-json. As there, the dual is written without an amount.
+
+use locale::{names, Digits, Direction, Locale, PluralRule};
+
+pub static AR: Locale = Locale {
+    tag: "ar",
+    units: [
+        names("ثانية", "ثوان", &["ثوانٍ"]).with_dual("ثانيتان"),
+        names("دقيقة", "دقائق", &[]).with_dual("دقيقتان"),
+        names("ساعة", "ساعات", &[]).with_dual("ساعتان"),
+        names("يوم", "أيام", &[]).with_dual("يومان"),
+        names("سنة", "سنوات", &[]).with_dual("سنتان"),
+    ],
+    and: "و",
+    separator: "، ",
+    spacing: " ",
+    plural: PluralRule::Arabic,
+    direction: Direction::RightToLeft,
+    digits: Digits::ArabicIndic,
+};
+
+#[cfg(test)]
+mod tests {
+    use duration::Duration;
+    use locale::{Digits, AR};
+
+    #[test]
+    fn test_ar() {
+        let d = Duration::new(93_660);
+        assert!(AR.format(&d) == "١ يوم، ساعتان و ١ دقيقة");
+        assert!(AR.format(&Duration::new(9000)) == "ساعتان و ٣٠ دقيقة");
+        assert!(AR.format(&Duration::new(2 * 86_400 + 2)) == "يومان و ثانيتان");
+        assert!(AR.format(&Duration::new(102 * 60)) == "١ ساعة و ٤٢ دقيقة");
+        assert!(AR.format(&Duration::new(11 * 60)) == "١١ دقيقة");
+        assert!(AR.format_digits(&Duration::new(3 * 3600), Digits::Latin) == "3 ساعات");
+        assert!(AR.parse(&AR.format(&d)) == Ok(d));
+        assert!(AR.parse("ساعتان و ٣٠ دقيقة") == Ok(Duration::new(9000)));
+        assert!(AR.parse("2 ساعتان و ٣٠ دقيقة") == Ok(Duration::new(9000)));
+        assert!(AR.direction.isolate("٣ ساعات") == "\u{2067}٣ ساعات\u{2069}");
+    }
+}
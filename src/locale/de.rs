@@ -0,0 +1,36 @@
+//! Unit names and plural rule of the CLDR `de` locale, from the long unit patterns of
+//! `units.json` in cldr-json.
+
+use locale::{names, Digits, Direction, Locale, PluralRule};
+
+pub static DE: Locale = Locale {
+    tag: "de",
+    units: [
+        names("Sekunde", "Sekunden", &[]),
+        names("Minute", "Minuten", &[]),
+        names("Stunde", "Stunden", &[]),
+        names("Tag", "Tage", &["Tagen"]),
+        names("Jahr", "Jahre", &["Jahren"]),
+    ],
+    and: "und",
+    separator: ", ",
+    spacing: " ",
+    plural: PluralRule::NotOne,
+    direction: Direction::LeftToRight,
+    digits: Digits::Latin,
+};
+
+#[cfg(test)]
+mod tests {
+    use duration::Duration;
+    use locale::{find, DE};
+
+    #[test]
+    fn test_de() {
+        assert!(DE.parse("2 Stunden") == Ok(Duration::new(7200)));
+        assert!(DE.parse("1 Tag, 2 stunden und 1 Minute") == Ok(Duration::new(93_660)));
+        assert!(DE.format(&Duration::new(93_660)) == "1 Tag, 2 Stunden und 1 Minute");
+        assert!(DE.parse(&DE.format(&Duration::new(35_344_799))) == Ok(Duration::new(35_344_799)));
+        assert!(find("de") == Some(&DE));
+    }
+}
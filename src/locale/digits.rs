@@ -0,0 +1,74 @@
+use std::fmt;
+
+/// The script decimal digits are written in, independent of the language of a
+/// [`Locale`](struct.Locale.html), ex) Latin digits in an Arabic UI showing numeric data.
+#[derive(PartialEq, Eq, Clone, Copy, Debug, Default)]
+pub enum Digits {
+    /// 0123456789
+    #[default]
+    Latin,
+    /// ٠١٢٣٤٥٦٧٨٩, used with Arabic.
+    ArabicIndic,
+    /// ۰۱۲۳۴۵۶۷۸۹, used with Persian and Urdu.
+    ExtendedArabicIndic,
+}
+
+const SCRIPTS: [Digits; 3] = [Digits::Latin, Digits::ArabicIndic, Digits::ExtendedArabicIndic];
+
+impl Digits {
+    fn zero(self) -> char {
+        match self {
+            Digits::Latin => '0',
+            Digits::ArabicIndic => '\u{660}',
+            Digits::ExtendedArabicIndic => '\u{6f0}',
+        }
+    }
+
+    /// Writes `n` in decimal digits of `self`, ex) "٤٢" for 42 in `ArabicIndic`.
+    pub fn write<W: fmt::Write>(self, w: &mut W, n: usize) -> fmt::Result {
+        if self == Digits::Latin {
+            return write!(w, "{}", n);
+        }
+        let zero = self.zero() as u32;
+        for c in n.to_string().chars() {
+            let digit = c as u32 - '0' as u32;
+            w.write_char(char::from_u32(zero + digit).unwrap_or(c))?;
+        }
+        Ok(())
+    }
+
+    /// Like [`write`](#method.write), returning a `String`.
+    pub fn format(self, n: usize) -> String {
+        let mut s = String::new();
+        let _ = self.write(&mut s, n);
+        s
+    }
+}
+
+/// Replaces digits of any supported script in `s` with Latin ones, so amounts read the same
+/// regardless of the script they were written in.
+pub(crate) fn to_latin(s: &str) -> String {
+    s.chars()
+        .map(|c| {
+            SCRIPTS
+                .iter()
+                .filter_map(|script| (c as u32).checked_sub(script.zero() as u32))
+                .find(|&digit| digit < 10)
+                .map_or(c, |digit| (b'0' + digit as u8) as char)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use locale::digits::to_latin;
+    use locale::Digits;
+
+    #[test]
+    fn test_digits() {
+        assert!(Digits::Latin.format(42) == "42");
+        assert!(Digits::ArabicIndic.format(42) == "٤٢");
+        assert!(Digits::ExtendedArabicIndic.format(1090) == "۱۰۹۰");
+        assert!(to_latin("٤٢ ساعة و ۳ دقائق") == "42 ساعة و 3 دقائق");
+    }
+}
@@ -0,0 +1,35 @@
+//! Unit names and plural rule of the CLDR `fr` locale, from the long unit patterns of
+//! `units.json` in cldr-json.
+
+use locale::{names, Digits, Direction, Locale, PluralRule};
+
+pub static FR: Locale = Locale {
+    tag: "fr",
+    units: [
+        names("seconde", "secondes", &[]),
+        names("minute", "minutes", &[]),
+        names("heure", "heures", &[]),
+        names("jour", "jours", &[]),
+        names("an", "ans", &["année", "années"]),
+    ],
+    and: "et",
+    separator: ", ",
+    spacing: " ",
+    plural: PluralRule::AboveOne,
+    direction: Direction::LeftToRight,
+    digits: Digits::Latin,
+};
+
+#[cfg(test)]
+mod tests {
+    use duration::Duration;
+    use locale::FR;
+
+    #[test]
+    fn test_fr() {
+        assert!(FR.parse("3 heures et 5 minutes") == Ok(Duration::new(11_100)));
+        assert!(FR.parse("2 années") == Ok(Duration::new(63_072_000)));
+        assert!(FR.format(&Duration::new(11_100)) == "3 heures et 5 minutes");
+        assert!(FR.format(&Duration::ZERO) == "0 seconde");
+    }
+}
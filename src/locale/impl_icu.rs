@@ -0,0 +1,92 @@
+use fixed_decimal::Decimal;
+use icu_experimental::dimension::units::formatter::UnitsFormatter;
+use icu_experimental::dimension::units::options::{UnitsFormatterOptions, Width};
+use icu_list::options::{ListFormatterOptions, ListLength};
+use icu_list::ListFormatter;
+use icu_locale_core::Locale as IcuLocale;
+
+use duration::{Duration, FormatOptions, Style, TimeUnitKind};
+use error::Error;
+
+/// Formats durations with the unit names, plural rules and list patterns of ICU4X, using the
+/// units and [`FormatOptions`](../duration/struct.FormatOptions.html) of this crate.
+pub struct IcuFormatter {
+    /// Ordered by [`TimeUnitKind`](../duration/enum.TimeUnitKind.html) discriminant.
+    units: Vec<UnitsFormatter>,
+    list: ListFormatter,
+    options: FormatOptions,
+}
+
+const ICU_UNITS: [&str; 5] = ["second", "minute", "hour", "day", "year"];
+
+impl IcuFormatter {
+    /// Loads the compiled ICU4X data of the BCP-47 `tag`, ex) "de-AT". Compact styles use
+    /// narrow unit names, ex) "2h", and the others long ones. Returns `Error::InvalidFormat` for
+    /// a malformed tag or missing data.
+    pub fn try_new(tag: &str, options: FormatOptions) -> Result<Self, Error> {
+        let locale = IcuLocale::try_from_str(tag).map_err(|_| Error::InvalidFormat)?;
+        let (width, length) = match options.style {
+            Style::Compact => (Width::Narrow, ListLength::Narrow),
+            _ => (Width::Long, ListLength::Wide),
+        };
+        let units = ICU_UNITS
+            .iter()
+            .map(|unit| {
+                UnitsFormatter::try_new((&locale).into(), unit, UnitsFormatterOptions::from(width))
+            })
+            .collect::<Result<Vec<UnitsFormatter>, _>>()
+            .map_err(|_| Error::InvalidFormat)?;
+        let list_options = ListFormatterOptions::default().with_length(length);
+        let list = ListFormatter::try_new_unit((&locale).into(), list_options)
+            .map_err(|_| Error::InvalidFormat)?;
+        Ok(IcuFormatter { units, list, options })
+    }
+
+    /// Formats `d`, keeping at most `max_units` units, or only the rounded largest one in
+    /// `Style::Approximate`. Zero formats as no seconds, ex) "0 seconds".
+    pub fn format(&self, d: &Duration) -> String {
+        let mut units = match self.options.style {
+            Style::Approximate => d.approximate_unit().into_iter().collect::<Vec<_>>(),
+            _ => {
+                let max_units = self.options.max_units.unwrap_or(usize::MAX);
+                d.iter_units().take(max_units).cloned().collect()
+            }
+        }
+        .into_iter()
+        .map(|unit| (Decimal::from(unit.amount), unit.kind))
+        .collect::<Vec<(Decimal, TimeUnitKind)>>();
+        if units.is_empty() {
+            units.push((Decimal::from(0u8), TimeUnitKind::Seconds));
+        }
+        let formatted = units
+            .iter()
+            .map(|(amount, kind)| self.units[u8::from(*kind) as usize].format_fixed_decimal(amount));
+        self.list.format_to_string(formatted)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use duration::{Duration, FormatOptions, Style};
+    use error::Error;
+    use locale::IcuFormatter;
+
+    #[test]
+    fn test_icu_formatter() {
+        let d = Duration::new(7199);
+        let en = IcuFormatter::try_new("en", FormatOptions::default()).unwrap();
+        assert!(en.format(&d) == "1 hour, 59 minutes, 59 seconds");
+        assert!(en.format(&Duration::ZERO) == "0 seconds");
+
+        let options = FormatOptions { max_units: Some(2), ..FormatOptions::default() };
+        assert!(IcuFormatter::try_new("de-AT", options).unwrap().format(&d) == "1 Stunde, 59 Minuten");
+        let ar = IcuFormatter::try_new("ar", FormatOptions::default()).unwrap();
+        assert!(ar.format(&Duration::new(9000)) == "ساعتان و30 دقيقة");
+
+        let options = FormatOptions { style: Style::Compact, ..FormatOptions::default() };
+        assert!(IcuFormatter::try_new("en", options).unwrap().format(&d) == "1h 59m 59s");
+        let options = FormatOptions { style: Style::Approximate, ..FormatOptions::default() };
+        assert!(IcuFormatter::try_new("fr", options).unwrap().format(&Duration::new(160)) == "3 minutes");
+        assert!(IcuFormatter::try_new("not a tag", FormatOptions::default()).err() == Some(Error::InvalidFormat));
+    }
+}
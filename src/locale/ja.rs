@@ -0,0 +1,37 @@
+//! Unit names and plural rule of the CLDR `ja` locale, from the long unit patterns of
+//! `units.json` in cldr-json.
+
+use locale::{names, Digits, Direction, Locale, PluralRule};
+
+pub static JA: Locale = Locale {
+    tag: "ja",
+    units: [
+        names("秒", "秒", &["秒間"]),
+        names("分", "分", &["分間"]),
+        names("時間", "時間", &[]),
+        names("日", "日", &["日間"]),
+        names("年", "年", &["年間"]),
+    ],
+    and: "",
+    separator: "",
+    spacing: "",
+    plural: PluralRule::Never,
+    direction: Direction::LeftToRight,
+    digits: Digits::Latin,
+};
+
+#[cfg(test)]
+mod tests {
+    use duration::Duration;
+    use locale::JA;
+
+    #[test]
+    fn test_ja() {
+        let d = Duration::new(93_660);
+        assert!(JA.format(&d) == "1日2時間1分");
+        assert!(JA.format(&Duration::ZERO) == "0秒");
+        assert!(JA.parse("2時間30分") == Ok(Duration::new(9000)));
+        assert!(JA.parse("1日 2時間 1分") == Ok(d));
+        assert!(JA.parse(&JA.format(&Duration::new(35_344_799))) == Ok(Duration::new(35_344_799)));
+    }
+}
@@ -0,0 +1,386 @@
+use std::convert::TryFrom;
+use std::fmt::Write;
+
+use duration::{Duration, TimeUnitKind, KINDS};
+use error::Error;
+use join::{join_human, JoinOptions};
+use parse::{decimal_nanos, NANOS_PER_SEC};
+
+#[cfg(feature = "locale-ar")]
+mod ar;
+#[cfg(feature = "locale-de")]
+mod de;
+mod digits;
+#[cfg(feature = "locale-fr")]
+mod fr;
+#[cfg(feature = "icu")]
+mod impl_icu;
+#[cfg(feature = "locale-ja")]
+mod ja;
+mod tag;
+
+#[cfg(feature = "locale-ar")]
+pub use self::ar::AR;
+#[cfg(feature = "locale-de")]
+pub use self::de::DE;
+pub use self::digits::Digits;
+#[cfg(feature = "locale-fr")]
+pub use self::fr::FR;
+#[cfg(feature = "icu")]
+pub use self::impl_icu::IcuFormatter;
+#[cfg(feature = "locale-ja")]
+pub use self::ja::JA;
+pub use self::tag::{resolve, LanguageTag};
+
+/// Names of one time unit in a [`Locale`](struct.Locale.html).
+#[derive(PartialEq, Clone, Copy, Debug)]
+pub struct UnitNames {
+    pub singular: &'static str,
+    pub plural: &'static str,
+    /// The name of exactly two, written without the amount, ex) Arabic "ساعتان" for 2 hours.
+    /// Only used by locales whose [`PluralRule`](enum.PluralRule.html) has a dual.
+    pub dual: Option<&'static str>,
+    /// Further accepted spellings when parsing, ex) inflected forms.
+    pub aliases: &'static [&'static str],
+}
+
+impl UnitNames {
+    /// Sets the [`dual`](#structfield.dual) name, ex) "ساعتان" for hours.
+    pub const fn with_dual(mut self, dual: &'static str) -> Self {
+        self.dual = Some(dual);
+        self
+    }
+}
+
+/// Which amounts take the plural unit name in a [`Locale`](struct.Locale.html).
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub enum PluralRule {
+    /// All but 1, ex) English and German.
+    NotOne,
+    /// All above 1, ex) French "0 jour".
+    AboveOne,
+    /// 2 to 10, and the same in each hundred, ex) Arabic, which takes the singular from 11 on.
+    /// Exactly 2 takes the dual instead, where the unit has one.
+    Arabic,
+    /// None, ex) Japanese.
+    Never,
+}
+
+impl PluralRule {
+    pub fn is_plural(self, n: usize) -> bool {
+        match self {
+            PluralRule::NotOne => n != 1,
+            PluralRule::AboveOne => n > 1,
+            PluralRule::Arabic => (2..=10).contains(&(n % 100)),
+            PluralRule::Never => false,
+        }
+    }
+
+    /// Whether `n` takes the dual unit name, only 2 in Arabic.
+    pub fn is_dual(self, n: usize) -> bool {
+        self == PluralRule::Arabic && n == 2
+    }
+}
+
+/// The direction a language is written in.
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub enum Direction {
+    LeftToRight,
+    RightToLeft,
+}
+
+impl Direction {
+    /// Wraps `s` in Unicode directional isolates, so that embedding it in text of the other
+    /// direction neither reorders its units nor the text around it.
+    pub fn isolate(self, s: &str) -> String {
+        let open = match self {
+            Direction::LeftToRight => '\u{2066}',
+            Direction::RightToLeft => '\u{2067}',
+        };
+        format!("{}{}\u{2069}", open, s)
+    }
+}
+
+/// Words used to read and write durations in one language.
+#[derive(PartialEq, Clone, Copy, Debug)]
+pub struct Locale {
+    /// The BCP-47 language tag, ex) "de".
+    pub tag: &'static str,
+    /// Unit names, ordered by [`TimeUnitKind`](../duration/enum.TimeUnitKind.html) discriminant:
+    /// seconds, minutes, hours, days, years.
+    pub units: [UnitNames; 5],
+    /// The conjunction joining the last two units, ex) "and". When empty, the last two units
+    /// are joined by the separator like the others.
+    pub and: &'static str,
+    /// Placed between the other units, ex) ", ".
+    pub separator: &'static str,
+    /// Placed between an amount and its unit name, ex) " ", or nothing in Japanese "2時間".
+    pub spacing: &'static str,
+    pub plural: PluralRule,
+    pub direction: Direction,
+    /// The digits amounts are written in unless overridden.
+    pub digits: Digits,
+}
+
+pub(crate) const fn names(
+    singular: &'static str,
+    plural: &'static str,
+    aliases: &'static [&'static str],
+) -> UnitNames {
+    UnitNames {
+        singular,
+        plural,
+        dual: None,
+        aliases,
+    }
+}
+
+pub static EN: Locale = Locale {
+    tag: "en",
+    units: [
+        names("second", "seconds", &[]),
+        names("minute", "minutes", &[]),
+        names("hour", "hours", &[]),
+        names("day", "days", &[]),
+        names("year", "years", &[]),
+    ],
+    and: "and",
+    separator: ", ",
+    spacing: " ",
+    plural: PluralRule::NotOne,
+    direction: Direction::LeftToRight,
+    digits: Digits::Latin,
+};
+
+/// The locales compiled in, English always and the others behind the `locale-<tag>` feature of
+/// their tag.
+pub static BUILTIN: &[&Locale] = &[
+    &EN,
+    #[cfg(feature = "locale-ar")]
+    &AR,
+    #[cfg(feature = "locale-de")]
+    &DE,
+    #[cfg(feature = "locale-fr")]
+    &FR,
+    #[cfg(feature = "locale-ja")]
+    &JA,
+];
+
+/// The tags of the locales compiled in, ex) `["en", "de"]` with only `locale-de` enabled.
+pub fn available() -> impl Iterator<Item = &'static str> {
+    BUILTIN.iter().map(|locale| locale.tag)
+}
+
+/// Looks up a compiled-in locale by its exact tag, ignoring case.
+pub fn find(tag: &str) -> Option<&'static Locale> {
+    BUILTIN.iter().find(|locale| locale.tag.eq_ignore_ascii_case(tag)).cloned()
+}
+
+impl Locale {
+    /// Looks up the unit kind named by `word`, ignoring case.
+    fn unit_kind(&self, word: &str) -> Option<TimeUnitKind> {
+        let word = word.to_lowercase();
+        KINDS.iter().zip(self.units.iter()).find(|&(_, names)| {
+            names.singular.to_lowercase() == word
+                || names.plural.to_lowercase() == word
+                || names.dual.is_some_and(|dual| dual.to_lowercase() == word)
+                || names.aliases.iter().any(|alias| alias.to_lowercase() == word)
+        }).map(|(&kind, _)| kind)
+    }
+
+    /// Looks up the unit kind whose dual is `word`, ignoring case.
+    fn dual_kind(&self, word: &str) -> Option<TimeUnitKind> {
+        let word = word.to_lowercase();
+        KINDS
+            .iter()
+            .zip(self.units.iter())
+            .find(|&(_, names)| names.dual.is_some_and(|dual| dual.to_lowercase() == word))
+            .map(|(&kind, _)| kind)
+    }
+
+    /// Formats `d` as prose in the words and digits of `self`, largest unit first in reading
+    /// order, ex) "2 Stunden und 30 Minuten" in [`DE`](static.DE.html) or "ساعتان و ٣٠ دقيقة" in
+    /// [`AR`](static.AR.html), which right-to-left text lays out from the right. Zero formats
+    /// as no seconds, ex) "0 Sekunden".
+    pub fn format(&self, d: &Duration) -> String {
+        self.format_digits(d, self.digits)
+    }
+
+    /// Like [`format`](#method.format), writing amounts in `digits` rather than those of `self`.
+    pub fn format_digits(&self, d: &Duration, digits: Digits) -> String {
+        let mut units = d.iter_units().map(|unit| (unit.amount, unit.kind)).collect::<Vec<_>>();
+        if units.is_empty() {
+            units.push((0, TimeUnitKind::Seconds));
+        }
+        let items = units.into_iter().map(|(amount, kind)| {
+            let names = &self.units[u8::from(kind) as usize];
+            if let Some(dual) = names.dual.filter(|_| self.plural.is_dual(amount)) {
+                return dual.to_string();
+            }
+            let mut s = digits.format(amount);
+            let name = if self.plural.is_plural(amount) { names.plural } else { names.singular };
+            let _ = write!(s, "{}{}", self.spacing, name);
+            s
+        });
+        if self.and.is_empty() {
+            return items.collect::<Vec<String>>().join(self.separator);
+        }
+        let options = JoinOptions {
+            separator: self.separator,
+            conjunction: self.and,
+            serial_comma: false,
+            terminator: "",
+        };
+        join_human(items, &options)
+    }
+
+    /// Parses amounts followed by localized unit names, separated by spaces, commas or the
+    /// conjunction, ex) "2 Stunden und 30 Minuten" in [`DE`](static.DE.html). A trailing period
+    /// is accepted, so English [`Display`](../duration/struct.Duration.html#impl-Display)
+    /// output can be read back with [`EN`](static.EN.html). Decimal amounts carry into smaller
+    /// units, ex) "1.5 hours" is 90 minutes. Amounts may be written in any script of
+    /// [`Digits`](enum.Digits.html).
+    pub fn parse(&self, s: &str) -> Result<Duration, Error> {
+        let nanos = self.parse_nanos(s)?;
+        usize::try_from(nanos / NANOS_PER_SEC)
+            .map(Duration::new)
+            .map_err(|_| Error::Overflow)
+    }
+
+    /// Parses like [`parse`](#method.parse), keeping the total in nanoseconds.
+    pub(crate) fn parse_nanos(&self, s: &str) -> Result<u128, Error> {
+        let s = digits::to_latin(s.trim());
+        let s = s.strip_suffix('.').unwrap_or(&s);
+        let mut words = s
+            .split(|c: char| c.is_whitespace() || c == ',' || c == '،')
+            .flat_map(|word| match self.spacing {
+                "" => split_amounts(word),
+                _ => vec![word],
+            })
+            .filter(|word| !word.is_empty() && !word.eq_ignore_ascii_case(self.and));
+
+        let mut nanos: u128 = 0;
+        let mut any = false;
+        while let Some(word) = words.next() {
+            if let Some(kind) = self.dual_kind(word) {
+                nanos = nanos
+                    .checked_add(2 * kind.as_seconds() as u128 * NANOS_PER_SEC)
+                    .ok_or(Error::Overflow)?;
+                any = true;
+                continue;
+            }
+            let (int, frac) = match word.find('.') {
+                Some(dot) => (&word[..dot], &word[dot + 1..]),
+                None => (word, ""),
+            };
+            if int.is_empty()
+                || word.ends_with('.')
+                || !int.chars().chain(frac.chars()).all(|c| c.is_ascii_digit())
+            {
+                return Err(Error::InvalidFormat);
+            }
+            let int: u128 = int.parse().map_err(|_| Error::Overflow)?;
+            let kind = words
+                .next()
+                .ok_or(Error::InvalidFormat)
+                .and_then(|unit| self.unit_kind(unit).ok_or(Error::UnknownUnit))?;
+            nanos = decimal_nanos(int, frac, kind.as_seconds() as u128 * NANOS_PER_SEC)
+                .and_then(|unit_nanos| nanos.checked_add(unit_nanos))
+                .ok_or(Error::Overflow)?;
+            any = true;
+        }
+
+        if !any {
+            return Err(Error::InvalidFormat);
+        }
+        Ok(nanos)
+    }
+}
+
+/// Splits `word` where an amount meets a unit name, ex) "2時間30分" into "2", "時間", "30" and
+/// "分", for locales writing them without spacing.
+fn split_amounts(word: &str) -> Vec<&str> {
+    let is_amount = |c: char| c.is_ascii_digit() || c == '.';
+    let mut parts = Vec::new();
+    let mut start = 0;
+    let mut chars = word.char_indices().peekable();
+    while let Some((_, c)) = chars.next() {
+        if let Some(&(next, d)) = chars.peek() {
+            if is_amount(c) != is_amount(d) {
+                parts.push(&word[start..next]);
+                start = next;
+            }
+        }
+    }
+    parts.push(&word[start..]);
+    parts
+}
+
+/// Parses `s` with each locale of `locales` in order, returning the first successful parse along
+/// with the locale that read it, ex) "3 heures" with [`BUILTIN`](static.BUILTIN.html) yields
+/// 3 hours in [`FR`](static.FR.html). If no locale can read `s`, the error of the first one is
+/// returned.
+pub fn parse_any<'a>(s: &str, locales: &[&'a Locale]) -> Result<(Duration, &'a Locale), Error> {
+    let mut first_err = Error::InvalidFormat;
+    for (i, locale) in locales.iter().enumerate() {
+        match locale.parse(s) {
+            Ok(d) => return Ok((d, locale)),
+            Err(e) if i == 0 => first_err = e,
+            Err(_) => {}
+        }
+    }
+    Err(first_err)
+}
+
+#[cfg(test)]
+mod tests {
+    use duration::Duration;
+    use error::Error;
+    use locale::{available, find, parse_any, Digits, Direction, BUILTIN, EN};
+
+    #[test]
+    fn test_locale_parse() {
+        assert!(EN.parse("1 hour, 59 minutes and 59 seconds.") == Ok(Duration::new(7199)));
+    }
+
+    #[test]
+    fn test_locale_parse_errors() {
+        assert!(EN.parse("") == Err(Error::InvalidFormat));
+        assert!(EN.parse("2") == Err(Error::InvalidFormat));
+        assert!(EN.parse("hours") == Err(Error::InvalidFormat));
+        assert!(EN.parse("2 Stunden") == Err(Error::UnknownUnit));
+        assert!(EN.parse("1. hours") == Err(Error::InvalidFormat));
+        assert!(EN.parse(".5 hours") == Err(Error::InvalidFormat));
+    }
+
+    #[cfg(all(feature = "locale-de", feature = "locale-fr"))]
+    #[test]
+    fn test_parse_any() {
+        let (d, locale) = parse_any("3 heures", BUILTIN).unwrap();
+        assert!(d == Duration::new(10_800));
+        assert!(locale.tag == "fr");
+
+        assert!(parse_any("2 Stunden", BUILTIN).unwrap().1 == &super::DE);
+        assert!(parse_any("2 horas", BUILTIN) == Err(Error::UnknownUnit));
+    }
+
+    #[test]
+    fn test_locale_format() {
+        let d = Duration::new(93_660);
+        assert!(EN.format(&Duration::new(9000)) == "2 hours and 30 minutes");
+        assert!(EN.format(&d) == "1 day, 2 hours and 1 minute");
+        assert!(EN.format(&Duration::ZERO) == "0 seconds");
+        assert!(EN.format_digits(&d, Digits::ExtendedArabicIndic).starts_with("۱ day"));
+        assert!(EN.parse(&EN.format(&d)) == Ok(d));
+        assert!(EN.direction == Direction::LeftToRight);
+    }
+
+    #[test]
+    fn test_registry() {
+        assert!(available().next() == Some("en"));
+        assert!(available().count() == BUILTIN.len());
+        assert!(find("EN") == Some(&EN));
+        assert!(find("xx").is_none());
+        assert!(parse_any("2 hours", BUILTIN) == Ok((Duration::new(7200), &EN)));
+    }
+}
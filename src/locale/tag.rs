@@ -0,0 +1,116 @@
+use error::Error;
+use locale::{find, Locale, EN};
+
+/// The language, script and region subtags of a BCP-47 language tag, ex) "de-AT" or
+/// "zh-Hant-TW". Extensions and private use subtags are accepted and ignored.
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub struct LanguageTag {
+    /// Lowercase, ex) "de".
+    pub language: String,
+    /// Titlecase, ex) "Hant".
+    pub script: Option<String>,
+    /// Uppercase, ex) "AT" or "419".
+    pub region: Option<String>,
+}
+
+impl LanguageTag {
+    /// Parses `s`, separated by hyphens or, as POSIX locales are, underscores, ignoring case,
+    /// ex) "de_at" is "de-AT". Returns `Error::InvalidFormat` for a malformed tag.
+    pub fn parse(s: &str) -> Result<Self, Error> {
+        let mut subtags = s.split(['-', '_']).peekable();
+        let language = subtags.next().unwrap_or_default();
+        let alpha = |s: &str| s.chars().all(|c| c.is_ascii_alphabetic());
+        if !(2..=8).contains(&language.len()) || language.len() == 4 || !alpha(language) {
+            return Err(Error::InvalidFormat);
+        }
+
+        let script = subtags.next_if(|s| s.len() == 4 && alpha(s)).map(|s| {
+            let (first, rest) = s.split_at(1);
+            first.to_ascii_uppercase() + &rest.to_ascii_lowercase()
+        });
+        let region = subtags
+            .next_if(|s| {
+                (s.len() == 2 && alpha(s)) || (s.len() == 3 && s.chars().all(|c| c.is_ascii_digit()))
+            })
+            .map(|s| s.to_ascii_uppercase());
+        for subtag in subtags {
+            let valid = !subtag.is_empty() && subtag.len() <= 8;
+            if !valid || !subtag.chars().all(|c| c.is_ascii_alphanumeric()) {
+                return Err(Error::InvalidFormat);
+            }
+        }
+        Ok(LanguageTag {
+            language: language.to_ascii_lowercase(),
+            script,
+            region,
+        })
+    }
+
+    /// The tags to try from most to least specific, ex) "de-Latn-AT", "de-Latn", "de-AT", then
+    /// "de". English is not included.
+    pub fn fallbacks(&self) -> Vec<String> {
+        let mut chain = Vec::new();
+        let language = &self.language;
+        match (&self.script, &self.region) {
+            (Some(script), Some(region)) => {
+                chain.push(format!("{}-{}-{}", language, script, region));
+                chain.push(format!("{}-{}", language, script));
+                chain.push(format!("{}-{}", language, region));
+            }
+            (Some(subtag), None) | (None, Some(subtag)) => {
+                chain.push(format!("{}-{}", language, subtag))
+            }
+            (None, None) => {}
+        }
+        chain.push(language.clone());
+        chain
+    }
+}
+
+/// The compiled-in locale best matching `tag`, trying its
+/// [`fallbacks`](struct.LanguageTag.html#method.fallbacks) in order and falling back to
+/// [`EN`](static.EN.html), ex) "de-AT" resolves to [`DE`](static.DE.html). Only a malformed
+/// tag is an error.
+pub fn resolve(tag: &str) -> Result<&'static Locale, Error> {
+    let tag = LanguageTag::parse(tag)?;
+    Ok(tag.fallbacks().iter().find_map(|tag| find(tag)).unwrap_or(&EN))
+}
+
+#[cfg(test)]
+mod tests {
+    use error::Error;
+    use locale::{resolve, LanguageTag, EN};
+
+    #[test]
+    fn test_language_tag_parse() {
+        let tag = LanguageTag::parse("zh_hant_tw").unwrap();
+        assert!(tag.language == "zh");
+        assert!(tag.script.as_deref() == Some("Hant"));
+        assert!(tag.region.as_deref() == Some("TW"));
+        assert!(LanguageTag::parse("es-419").unwrap().region.as_deref() == Some("419"));
+        assert!(LanguageTag::parse("de-CH-1996").unwrap().region.as_deref() == Some("CH"));
+        assert!(LanguageTag::parse("").is_err());
+        assert!(LanguageTag::parse("d").is_err());
+        assert!(LanguageTag::parse("de--AT") == Err(Error::InvalidFormat));
+        assert!(LanguageTag::parse("de-AT-toolongsubtag") == Err(Error::InvalidFormat));
+    }
+
+    #[test]
+    fn test_fallbacks() {
+        let tag = LanguageTag::parse("de-Latn-AT").unwrap();
+        assert!(tag.fallbacks() == ["de-Latn-AT", "de-Latn", "de-AT", "de"]);
+        assert!(LanguageTag::parse("de-AT").unwrap().fallbacks() == ["de-AT", "de"]);
+        assert!(LanguageTag::parse("en").unwrap().fallbacks() == ["en"]);
+    }
+
+    #[test]
+    fn test_resolve() {
+        assert!(resolve("en-GB") == Ok(&EN));
+        assert!(resolve("pt-BR") == Ok(&EN));
+        assert!(resolve("not a tag") == Err(Error::InvalidFormat));
+        #[cfg(feature = "locale-de")]
+        assert!(resolve("de-AT") == Ok(&::locale::DE));
+        #[cfg(feature = "locale-fr")]
+        assert!(resolve("fr_CA").unwrap().tag == "fr");
+    }
+}
@@ -0,0 +1,162 @@
+//! Conversions between durations and the counts of media tooling, ex) video frames, audio
+//! samples or musical beats.
+//!
+//! As a [`Duration`](../duration/struct.Duration.html) counts whole seconds, converting a count
+//! into one truncates the part below a second.
+
+use std::convert::TryFrom;
+
+use duration::{Duration, Rounding};
+
+/// The nominal, whole frame rate which timecode counts frames in, ex) 30 for 29.97 fps.
+fn nominal_fps(fps: f64) -> u64 {
+    fps.round().max(1.0) as u64
+}
+
+/// Formats `frames` as SMPTE non-drop-frame timecode, `HH:MM:SS:FF`, ex) "01:59:59:12" at 24
+/// fps. Fractional rates count frames at the nearest whole rate, ex) 30 for 29.97 fps. Hours
+/// grow past two digits rather than wrap.
+pub fn timecode(frames: u64, fps: f64) -> String {
+    let fps = nominal_fps(fps);
+    let secs = frames / fps;
+    format!(
+        "{:02}:{:02}:{:02}:{:02}",
+        secs / 3600,
+        secs % 3600 / 60,
+        secs % 60,
+        frames % fps
+    )
+}
+
+impl Duration {
+    /// The duration of `frames` frames at `fps` frames per second, ex) 48 frames at 24 fps are 2
+    /// seconds. Returns `None` unless `fps` is positive and finite.
+    pub fn from_frames(frames: u64, fps: f64) -> Option<Duration> {
+        if fps.is_nan() || fps <= 0.0 || fps.is_infinite() {
+            return None;
+        }
+        // Float to integer casts saturate.
+        Some(Duration::new((frames as f64 / fps) as usize))
+    }
+
+    /// The amount of frames shown during `self` at `fps`, rounded down.
+    pub fn to_frames(&self, fps: f64) -> u64 {
+        (self.total_seconds() as f64 * fps) as u64
+    }
+
+    /// Formats `self` as SMPTE [`timecode`](../media/fn.timecode.html) at `fps`. Frames are
+    /// always zero, as `self` counts whole seconds.
+    pub fn to_timecode(&self, fps: f64) -> String {
+        let fps = nominal_fps(fps);
+        timecode((self.total_seconds() as u64).saturating_mul(fps), fps as f64)
+    }
+
+    /// The duration of `samples` samples at `sample_rate` Hz, rounded to whole seconds by
+    /// `rounding`, ex) 66,150 samples at 44.1 kHz are 1.5 seconds, so 1 second rounded down and 2
+    /// seconds rounded to the nearest. Returns `None` for a zero `sample_rate`, or if the seconds
+    /// exceed `usize`.
+    pub fn from_samples(samples: u64, sample_rate: u32, rounding: Rounding) -> Option<Duration> {
+        if sample_rate == 0 {
+            return None;
+        }
+        let secs = rounding.div(u128::from(samples), u128::from(sample_rate));
+        usize::try_from(secs).ok().map(Duration::new)
+    }
+
+    /// The amount of samples in `self` at `sample_rate` Hz, saturating.
+    pub fn to_samples(&self, sample_rate: u32) -> u64 {
+        (self.total_seconds() as u64).saturating_mul(u64::from(sample_rate))
+    }
+
+    /// The duration of `beats` beats, which may be fractional, at `bpm` beats per minute, rounded
+    /// down, ex) 8 beats at 120 bpm are 4 seconds. Returns `None` unless both are finite and
+    /// `bpm` is positive, or for negative `beats`.
+    pub fn from_beats(beats: f64, bpm: f64) -> Option<Duration> {
+        if !beats.is_finite() || !bpm.is_finite() || beats < 0.0 || bpm <= 0.0 {
+            return None;
+        }
+        Some(Duration::new((beats * 60.0 / bpm) as usize))
+    }
+
+    /// The amount of beats in `self` at `bpm` beats per minute, ex) 4 seconds at 120 bpm are 8.
+    pub fn to_beats(&self, bpm: f64) -> f64 {
+        self.total_seconds() as f64 * bpm / 60.0
+    }
+
+    /// The amount of bars in `self` at `bpm` with `beats_per_bar` beats each, ex) 4 seconds at
+    /// 120 bpm in 4/4 are 2 bars.
+    pub fn to_bars(&self, bpm: f64, beats_per_bar: u32) -> f64 {
+        self.to_beats(bpm) / f64::from(beats_per_bar)
+    }
+
+    /// Formats `self` as whole bars and leftover beats, with at most two decimals, ex) "2 bars"
+    /// or "1 bar and 1.5 beats".
+    pub fn format_bars(&self, bpm: f64, beats_per_bar: u32) -> String {
+        let beats = self.to_beats(bpm);
+        let bars = if beats_per_bar == 0 { 0.0 } else { (beats / f64::from(beats_per_bar)).floor() };
+        let rest = beats - bars * f64::from(beats_per_bar);
+        let rest = format!("{:.2}", rest);
+        let rest = rest.trim_end_matches('0').trim_end_matches('.');
+
+        let plural = |n: &str| if n == "1" { "" } else { "s" };
+        let bars = bars.to_string();
+        match (bars.as_str(), rest) {
+            ("0", rest) => format!("{} beat{}", rest, plural(rest)),
+            (bars, "0") => format!("{} bar{}", bars, plural(bars)),
+            (bars, rest) => format!("{} bar{} and {} beat{}", bars, plural(bars), rest, plural(rest)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use duration::{Duration, Rounding};
+    use media::timecode;
+
+    #[test]
+    fn test_frames() {
+        assert!(Duration::from_frames(48, 24.0) == Some(Duration::new(2)));
+        assert!(Duration::from_frames(59, 24.0) == Some(Duration::new(2)));
+        assert!(Duration::from_frames(1, 0.0).is_none());
+        assert!(Duration::new(10).to_frames(29.97) == 299);
+        assert!(Duration::new(10).to_frames(25.0) == 250);
+    }
+
+    #[test]
+    fn test_timecode() {
+        assert!(timecode(7199 * 24 + 12, 24.0) == "01:59:59:12");
+        assert!(timecode(29, 29.97) == "00:00:00:29");
+        assert!(timecode(30, 29.97) == "00:00:01:00");
+        assert!(Duration::new(7199).to_timecode(25.0) == "01:59:59:00");
+    }
+
+    #[test]
+    fn test_samples() {
+        assert!(Duration::from_samples(66_150, 44_100, Rounding::Down) == Some(Duration::new(1)));
+        assert!(Duration::from_samples(66_150, 44_100, Rounding::Nearest) == Some(Duration::new(2)));
+        assert!(Duration::from_samples(44_101, 44_100, Rounding::Up) == Some(Duration::new(2)));
+        assert!(Duration::from_samples(44_100, 44_100, Rounding::Up) == Some(Duration::new(1)));
+        assert!(Duration::from_samples(1, 0, Rounding::Down).is_none());
+        let most = Duration::from_samples(u64::MAX, 1, Rounding::Down);
+        assert!(most.map(|d| d.as_secs()) == Some(u64::MAX));
+        assert!(Duration::new(90).to_samples(48_000) == 4_320_000);
+    }
+
+    #[test]
+    fn test_beats() {
+        assert!(Duration::from_beats(8.0, 120.0) == Some(Duration::new(4)));
+        assert!(Duration::from_beats(2.5, 60.0) == Some(Duration::new(2)));
+        assert!(Duration::from_beats(1.0, 0.0).is_none());
+        assert!(Duration::from_beats(-1.0, 120.0).is_none());
+        assert!(Duration::new(4).to_beats(120.0) == 8.0);
+        assert!(Duration::new(4).to_bars(120.0, 4) == 2.0);
+    }
+
+    #[test]
+    fn test_format_bars() {
+        assert!(Duration::new(4).format_bars(120.0, 4) == "2 bars");
+        assert!(Duration::new(3).format_bars(110.0, 4) == "1 bar and 1.5 beats");
+        assert!(Duration::new(1).format_bars(60.0, 4) == "1 beat");
+        assert!(Duration::new(0).format_bars(60.0, 4) == "0 beats");
+    }
+}
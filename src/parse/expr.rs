@@ -0,0 +1,185 @@
+use error::Error;
+use parse::Parser;
+
+/// Parentheses nested deeper than this are rejected rather than risking the stack.
+const MAX_DEPTH: usize = 32;
+
+#[derive(PartialEq, Clone, Copy, Debug)]
+enum Token<'a> {
+    Open,
+    Close,
+    Plus,
+    Minus,
+    Times,
+    /// A duration such as "1h 30m" or "2 hours", or a bare integer multiplier.
+    Operand(&'a str),
+}
+
+#[derive(PartialEq, Clone, Copy, Debug)]
+enum Value {
+    Integer(i128),
+    Nanos(i128),
+}
+
+fn tokenize<'a>(s: &'a str) -> Vec<Token<'a>> {
+    let mut tokens = Vec::new();
+    let mut start = 0;
+    for (i, c) in s.char_indices() {
+        let token = match c {
+            '(' => Token::Open,
+            ')' => Token::Close,
+            '+' => Token::Plus,
+            '-' => Token::Minus,
+            '*' => Token::Times,
+            _ => continue,
+        };
+        push_operand(&mut tokens, &s[start..i]);
+        tokens.push(token);
+        start = i + c.len_utf8();
+    }
+    push_operand(&mut tokens, &s[start..]);
+    tokens
+}
+
+fn push_operand<'a>(tokens: &mut Vec<Token<'a>>, s: &'a str) {
+    let s = s.trim();
+    if !s.is_empty() {
+        tokens.push(Token::Operand(s));
+    }
+}
+
+struct Eval<'a, 'p> {
+    tokens: Vec<Token<'a>>,
+    pos: usize,
+    depth: usize,
+    parser: &'p Parser,
+}
+
+impl<'a, 'p> Eval<'a, 'p> {
+    fn peek(&self) -> Option<Token<'a>> {
+        self.tokens.get(self.pos).cloned()
+    }
+
+    fn next(&mut self) -> Option<Token<'a>> {
+        let token = self.peek();
+        self.pos += 1;
+        token
+    }
+
+    /// sum := product (("+" | "-") product)*
+    fn sum(&mut self) -> Result<Value, Error> {
+        let mut value = self.product()?;
+        while let Some(op) = self.peek().filter(|t| *t == Token::Plus || *t == Token::Minus) {
+            self.pos += 1;
+            let (a, b) = match (value, self.product()?) {
+                (Value::Nanos(a), Value::Nanos(b)) => (a, b),
+                _ => return Err(Error::InvalidFormat),
+            };
+            let nanos = if op == Token::Plus {
+                a.checked_add(b)
+            } else {
+                a.checked_sub(b)
+            };
+            value = Value::Nanos(nanos.ok_or(Error::Overflow)?);
+        }
+        Ok(value)
+    }
+
+    /// product := atom ("*" atom)*
+    fn product(&mut self) -> Result<Value, Error> {
+        let mut value = self.atom()?;
+        while self.peek() == Some(Token::Times) {
+            self.pos += 1;
+            value = match (value, self.atom()?) {
+                (Value::Integer(a), Value::Integer(b)) => a.checked_mul(b).map(Value::Integer),
+                (Value::Integer(n), Value::Nanos(d)) | (Value::Nanos(d), Value::Integer(n)) => {
+                    n.checked_mul(d).map(Value::Nanos)
+                }
+                (Value::Nanos(_), Value::Nanos(_)) => return Err(Error::InvalidFormat),
+            }
+            .ok_or(Error::Overflow)?;
+        }
+        Ok(value)
+    }
+
+    /// atom := "(" sum ")" | integer | duration
+    fn atom(&mut self) -> Result<Value, Error> {
+        match self.next() {
+            Some(Token::Open) => {
+                if self.depth == MAX_DEPTH {
+                    return Err(Error::InvalidFormat);
+                }
+                self.depth += 1;
+                let value = self.sum()?;
+                self.depth -= 1;
+                match self.next() {
+                    Some(Token::Close) => Ok(value),
+                    _ => Err(Error::InvalidFormat),
+                }
+            }
+            Some(Token::Operand(s)) if s.chars().all(|c| c.is_ascii_digit()) => {
+                s.parse().map(Value::Integer).map_err(|_| Error::Overflow)
+            }
+            Some(Token::Operand(s)) => {
+                let nanos = self.parser.parse_nanos(s)?;
+                if nanos > i128::MAX as u128 {
+                    return Err(Error::Overflow);
+                }
+                Ok(Value::Nanos(nanos as i128))
+            }
+            _ => Err(Error::InvalidFormat),
+        }
+    }
+}
+
+/// Evaluates the expression `s` to nanoseconds, parsing operands with `parser`.
+pub(crate) fn eval(s: &str, parser: &Parser) -> Result<u128, Error> {
+    let mut eval = Eval {
+        tokens: tokenize(s),
+        pos: 0,
+        depth: 0,
+        parser,
+    };
+    let value = eval.sum()?;
+    if eval.pos != eval.tokens.len() {
+        return Err(Error::InvalidFormat);
+    }
+    match value {
+        Value::Nanos(nanos) if nanos < 0 => Err(Error::Overflow),
+        Value::Nanos(nanos) => Ok(nanos as u128),
+        Value::Integer(_) => Err(Error::InvalidFormat),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use duration::Duration;
+    use error::Error;
+    use parse::{parse_expr, Parser};
+
+    #[test]
+    fn test_parse_expr() {
+        assert!(parse_expr("1h + 30m - 15s") == Ok(Duration::new(5385)));
+        assert!(parse_expr("2 * (1h + 5m)") == Ok(Duration::new(7800)));
+        assert!(parse_expr("90s * 3") == Ok(Duration::new(270)));
+        assert!(parse_expr("500ms * 4") == Ok(Duration::new(2)));
+        assert!(parse_expr("1 hour - 5 minutes") == Ok(Duration::new(3300)));
+        assert!(parse_expr("15s - 1m + 1m") == Ok(Duration::new(15)));
+        assert!(parse_expr("1h30m") == Ok(Duration::new(5400)));
+
+        let parser = Parser::builder().alias("sprint", Duration::new(14 * 86_400)).build();
+        assert!(parser.parse_expr("2sprint - 1d") == Ok(Duration::new(27 * 86_400)));
+    }
+
+    #[test]
+    fn test_parse_expr_errors() {
+        assert!(parse_expr("1m - 1h") == Err(Error::Overflow));
+        assert!(parse_expr("1h * 1h") == Err(Error::InvalidFormat));
+        assert!(parse_expr("1h + 2") == Err(Error::InvalidFormat));
+        assert!(parse_expr("2 * 3") == Err(Error::InvalidFormat));
+        assert!(parse_expr("(1h + 5m") == Err(Error::InvalidFormat));
+        assert!(parse_expr("1h +") == Err(Error::InvalidFormat));
+        assert!(parse_expr("") == Err(Error::InvalidFormat));
+        assert!(parse_expr(&format!("{}1h{}", "(".repeat(64), ")".repeat(64))) == Err(Error::InvalidFormat));
+    }
+}
@@ -0,0 +1,649 @@
+use std::convert::TryFrom;
+use std::ops::Range;
+use std::str::{self, FromStr};
+
+use duration::{Duration, TimeUnitKind};
+use error::Error;
+use locale::EN;
+
+mod expr;
+
+pub(crate) const NANOS_PER_SEC: u128 = 1_000_000_000;
+
+/// Keywords which introduce a duration in common log formats, ex) "took 1.234s".
+const LOG_KEYWORDS: [&str; 6] = ["took", "elapsed", "duration", "after", "time", "in"];
+
+/// Custom unit names along with their length in nanoseconds.
+type Aliases = [(String, u128)];
+
+/// The amount of nanoseconds in one of the shorthand unit suffixes, ex) "ms" or "h", or in one of
+/// `aliases`, which take precedence.
+fn unit_nanos(unit: &str, aliases: &Aliases) -> Option<u128> {
+    if let Some(&(_, nanos)) = aliases.iter().find(|(name, _)| name == unit) {
+        return Some(nanos);
+    }
+    match unit {
+        "ns" => Some(1),
+        "us" | "µs" => Some(1_000),
+        "ms" => Some(1_000_000),
+        _ => unit.parse::<TimeUnitKind>()
+            .ok()
+            .map(|kind| kind.as_seconds() as u128 * NANOS_PER_SEC),
+    }
+}
+
+/// Multiplies the decimal amount `int.frac` by `unit` nanoseconds, carrying the fraction exactly
+/// into smaller units, ex) 1.25 hours is 1 hour and 15 minutes. A fraction that does not amount to
+/// a whole nanosecond is truncated, ex) "1.0000000001s" is 1 second. `frac` must be ascii digits.
+pub(crate) fn decimal_nanos(int: u128, frac: &str, unit: u128) -> Option<u128> {
+    // Digits past the 18th cannot amount to a nanosecond of any unit.
+    let kept = &frac[..frac.len().min(18)];
+    let frac_nanos = if kept.is_empty() {
+        0
+    } else {
+        kept.parse::<u128>().ok()?.checked_mul(unit)? / 10u128.pow(kept.len() as u32)
+    };
+    int.checked_mul(unit)?.checked_add(frac_nanos)
+}
+
+/// Scans a run of shorthand components at the start of `s`, ex) "2m03s" or "1.234s", without
+/// anything separating the components. Returns the total in nanoseconds and the amount of bytes
+/// consumed, or `None` if `s` does not start with a component.
+fn scan_shorthand(s: &str, aliases: &Aliases) -> Option<(u128, usize)> {
+    let mut total: u128 = 0;
+    let mut pos = 0;
+
+    while s[pos..].starts_with(|c: char| c.is_ascii_digit()) {
+        let rest = &s[pos..];
+        let int_len = rest.find(|c: char| !c.is_ascii_digit()).unwrap_or(rest.len());
+        let int: u128 = rest[..int_len].parse().ok()?;
+
+        let mut len = int_len;
+        let mut frac = "";
+        if rest[len..].starts_with('.') {
+            let digits = &rest[len + 1..];
+            let frac_len = digits.find(|c: char| !c.is_ascii_digit()).unwrap_or(digits.len());
+            if frac_len == 0 {
+                return None;
+            }
+            frac = &digits[..frac_len];
+            len += 1 + frac_len;
+        }
+
+        let unit_len = rest[len..]
+            .find(|c: char| !c.is_alphabetic())
+            .unwrap_or(rest.len() - len);
+        let nanos = unit_nanos(&rest[len..len + unit_len], aliases)?;
+        len += unit_len;
+
+        total = total.checked_add(decimal_nanos(int, frac, nanos)?)?;
+        pos += len;
+    }
+
+    if pos == 0 {
+        None
+    } else {
+        Some((total, pos))
+    }
+}
+
+/// Parses whitespace separated runs of shorthand components, ex) "1h 59m 59s" or "1h59m59s",
+/// into nanoseconds.
+fn parse_shorthand(s: &str, aliases: &Aliases) -> Result<u128, Error> {
+    let mut nanos: u128 = 0;
+    let mut any = false;
+    for chunk in s.split_whitespace() {
+        match scan_shorthand(chunk, aliases) {
+            Some((n, len)) if len == chunk.len() => {
+                nanos = nanos.checked_add(n).ok_or(Error::Overflow)?;
+            }
+            _ => return Err(Error::InvalidFormat),
+        }
+        any = true;
+    }
+
+    if !any {
+        return Err(Error::InvalidFormat);
+    }
+    Ok(nanos)
+}
+
+/// Converts a parsed total of nanoseconds, truncating below a second.
+fn from_nanos(nanos: u128) -> Result<Duration, Error> {
+    usize::try_from(nanos / NANOS_PER_SEC)
+        .map(Duration::new)
+        .map_err(|_| Error::Overflow)
+}
+
+/// How forgiving [`parse_with`](fn.parse_with.html) and [`Parser`](struct.Parser.html) are with user-typed input.
+#[derive(PartialEq, Clone, Copy, Debug, Default)]
+pub enum Strictness {
+    /// Only the exact forms of [`FromStr`](../duration/struct.Duration.html#impl-FromStr).
+    Strict,
+    /// Further ignores case, periods after unit abbreviations, spaces between amounts and units
+    /// and the separators ",", ";" and "and", ex) "2H", "2 Hrs." or "1 hr; 30 mins".
+    #[default]
+    Lenient,
+}
+
+/// The forms of input a [`Parser`](struct.Parser.html) accepts, so that services can pin them
+/// while later versions of the crate learn new ones. Each level accepts everything the previous
+/// one does.
+#[derive(PartialEq, Eq, PartialOrd, Ord, Clone, Copy, Debug, Default)]
+pub enum Grammar {
+    /// Shorthand only, ex) "1h 30m" or "1.5h". Lenient parsing only ignores case here, so that
+    /// prose like "1 hour and 30 minutes" or "1 hr; 30 mins" is rejected either way.
+    V1,
+    /// Also English prose, ex) "1 hour and 30 minutes". The grammar of
+    /// [`FromStr`](../duration/struct.Duration.html#impl-FromStr).
+    #[default]
+    V2,
+    /// Also expressions, ex) "1h + 30m - 15s", see
+    /// [`Parser::parse_expr`](struct.Parser.html#method.parse_expr).
+    V3,
+}
+
+/// Rewrites lenient input as shorthand, ex) "2 Hrs. and 5 min" -> "2hrs 5min".
+fn normalize(s: &str) -> String {
+    let lower = s.to_lowercase();
+    let words = lower
+        .split(|c: char| c.is_whitespace() || c == ',' || c == ';')
+        .map(|word| word.trim_end_matches('.'))
+        .filter(|word| !word.is_empty() && *word != "and");
+
+    let mut out = String::with_capacity(lower.len());
+    for word in words {
+        let joins_amount = out.ends_with(|c: char| c.is_ascii_digit())
+            && word.starts_with(char::is_alphabetic);
+        if !out.is_empty() && !joins_amount {
+            out.push(' ');
+        }
+        out.push_str(word);
+    }
+    out
+}
+
+/// Parses `s` like [`FromStr`](../duration/struct.Duration.html#impl-FromStr) and, unless
+/// `strictness` is `Strict`, retries messy input with the leniency of
+/// [`Strictness::Lenient`](enum.Strictness.html#variant.Lenient). Errors are those of the strict
+/// parse.
+pub fn parse_with(s: &str, strictness: Strictness) -> Result<Duration, Error> {
+    ParserBuilder::new().strictness(strictness).build().parse(s)
+}
+
+/// Evaluates `s` like [`Parser::parse_expr`](struct.Parser.html#method.parse_expr) with the
+/// default parser, ex) "1h + 30m - 15s".
+pub fn parse_expr(s: &str) -> Result<Duration, Error> {
+    ParserBuilder::new().build().parse_expr(s)
+}
+
+/// Bounds on the input of a [`Parser`](struct.Parser.html), so that it is safe to expose to
+/// untrusted input. Input beyond any of them is rejected with
+/// [`Error::LimitsExceeded`](../error/enum.Error.html#variant.LimitsExceeded) before parsing.
+/// The default is unlimited.
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub struct Limits {
+    /// The most bytes of input.
+    pub max_len: usize,
+    /// The most amounts in the input, ex) 3 in "1h 30m + 2s".
+    pub max_components: usize,
+    /// The largest whole amount of any component, ex) 90 in "90.5m".
+    pub max_amount: u64,
+}
+
+impl Default for Limits {
+    fn default() -> Self {
+        Limits {
+            max_len: usize::MAX,
+            max_components: usize::MAX,
+            max_amount: u64::MAX,
+        }
+    }
+}
+
+impl Limits {
+    /// Limits suitable for user input: 256 bytes, 16 components and amounts up to a billion.
+    pub fn untrusted() -> Self {
+        Limits {
+            max_len: 256,
+            max_components: 16,
+            max_amount: 1_000_000_000,
+        }
+    }
+
+    fn check(&self, s: &str) -> Result<(), Error> {
+        if s.len() > self.max_len {
+            return Err(Error::LimitsExceeded);
+        }
+        let mut components = 0;
+        let mut pos = 0;
+        while let Some(start) = s[pos..].find(|c: char| c.is_ascii_digit()).map(|i| pos + i) {
+            let len = s[start..].find(|c: char| !c.is_ascii_digit()).unwrap_or(s.len() - start);
+            let is_fraction = s[..start].ends_with('.')
+                && s[..start - 1].ends_with(|c: char| c.is_ascii_digit());
+            if !is_fraction {
+                components += 1;
+                let amount = s[start..start + len].parse::<u64>();
+                if components > self.max_components || amount.map_or(true, |a| a > self.max_amount) {
+                    return Err(Error::LimitsExceeded);
+                }
+            }
+            pos = start + len;
+        }
+        Ok(())
+    }
+}
+
+/// Parses arbitrary bytes like [`FromStr`](../duration/struct.Duration.html#impl-FromStr),
+/// returning `InvalidFormat` for input that is not UTF-8.
+///
+/// Any input returns `Ok` or `Err` without panicking, which makes this a suitable entry point
+/// for fuzzers and network services. The same holds for
+/// [`Parser::parse`](struct.Parser.html#method.parse) and
+/// [`Parser::parse_expr`](struct.Parser.html#method.parse_expr) in every configuration: the
+/// parsers only slice strings at positions found by searching them, use checked arithmetic on
+/// amounts and bound the nesting of parentheses. Pair it with a [`Parser`](struct.Parser.html)
+/// and [`Limits`](struct.Limits.html) to also bound the work per input.
+pub fn parse_no_panic(input: &[u8]) -> Result<Duration, Error> {
+    str::from_utf8(input)
+        .map_err(|_| Error::InvalidFormat)
+        .and_then(str::parse)
+}
+
+/// Configures a [`Parser`](struct.Parser.html), ex) with domain-specific unit names.
+#[derive(Clone, Default)]
+pub struct ParserBuilder {
+    strictness: Strictness,
+    grammar: Grammar,
+    limits: Limits,
+    aliases: Vec<(String, u128)>,
+}
+
+impl ParserBuilder {
+    pub fn new() -> Self {
+        ParserBuilder::default()
+    }
+
+    pub fn strictness(mut self, strictness: Strictness) -> Self {
+        self.strictness = strictness;
+        self
+    }
+
+    /// Accepts the forms of `grammar` and the levels below it, defaults to
+    /// [`Grammar::V2`](enum.Grammar.html#variant.V2).
+    pub fn grammar(mut self, grammar: Grammar) -> Self {
+        self.grammar = grammar;
+        self
+    }
+
+    /// Rejects input beyond `limits`, ex) [`Limits::untrusted`](struct.Limits.html#method.untrusted).
+    pub fn limits(mut self, limits: Limits) -> Self {
+        self.limits = limits;
+        self
+    }
+
+    /// Accepts `name` as a shorthand unit of length `unit`, ex) "fortnight" for 14 days or "q"
+    /// for a quarter of 91 days, so that "2fortnight" or "1q 5d" parse. Aliases take precedence
+    /// over the built-in units. Like those, they match lowercase only, so `name` is lowercased,
+    /// and further spellings such as plurals need an alias of their own.
+    pub fn alias(mut self, name: &str, unit: Duration) -> Self {
+        let nanos = unit.total_seconds() as u128 * NANOS_PER_SEC;
+        self.aliases.push((name.to_lowercase(), nanos));
+        self
+    }
+
+    pub fn build(self) -> Parser {
+        Parser {
+            strictness: self.strictness,
+            grammar: self.grammar,
+            limits: self.limits,
+            aliases: self.aliases,
+        }
+    }
+}
+
+/// Parses durations like [`parse_with`](fn.parse_with.html), with the custom unit names of a
+/// [`ParserBuilder`](struct.ParserBuilder.html).
+#[derive(Clone)]
+pub struct Parser {
+    strictness: Strictness,
+    grammar: Grammar,
+    limits: Limits,
+    aliases: Vec<(String, u128)>,
+}
+
+impl Parser {
+    pub fn builder() -> ParserBuilder {
+        ParserBuilder::new()
+    }
+
+    pub fn parse(&self, s: &str) -> Result<Duration, Error> {
+        self.limits.check(s)?;
+        if self.grammar >= Grammar::V3 {
+            expr::eval(s, self).and_then(from_nanos)
+        } else {
+            self.parse_nanos(s).and_then(from_nanos)
+        }
+    }
+
+    /// Evaluates sums, differences and integer multiples of durations, ex) "1h + 30m - 15s",
+    /// "2 * (1h + 5m)" or "90s * 3", regardless of the configured grammar. Each operand is parsed
+    /// like [`parse`](#method.parse) at grammar `V2` or below, so prose and aliases can be mixed
+    /// in, ex) "1 hour - 5 minutes". The result may not be negative, but intermediate values may.
+    pub fn parse_expr(&self, s: &str) -> Result<Duration, Error> {
+        self.limits.check(s)?;
+        expr::eval(s, self).and_then(from_nanos)
+    }
+
+    fn parse_nanos(&self, s: &str) -> Result<u128, Error> {
+        let strict = parse_shorthand(s, &self.aliases).or_else(|e| {
+            if self.grammar >= Grammar::V2 {
+                EN.parse_nanos(s)
+            } else {
+                Err(e)
+            }
+        });
+        match self.strictness {
+            Strictness::Strict => strict,
+            Strictness::Lenient => strict.or_else(|e| {
+                let normalized = normalize(s);
+                if self.grammar == Grammar::V1 && normalized != s.trim().to_lowercase() {
+                    return Err(e);
+                }
+                parse_shorthand(&normalized, &self.aliases).map_err(|_| e)
+            }),
+        }
+    }
+}
+
+impl Duration {
+    /// The shorthand unit suffixes accepted by [`FromStr`](#impl-FromStr), smallest first, ex)
+    /// for shell completions of "30" to "30s" or "30m".
+    pub const UNIT_SUFFIXES: &'static [&'static str] =
+        &["ns", "us", "µs", "ms", "s", "m", "h", "d", "y"];
+    /// Typical values of a duration argument, ex) to suggest for an empty command line flag.
+    pub const VALUE_HINTS: &'static [&'static str] = &["30s", "5m", "15m", "1h", "1d"];
+
+    /// Completion candidates for a partially typed duration argument: the
+    /// [`VALUE_HINTS`](#associatedconstant.VALUE_HINTS) starting with `prefix` or, if `prefix`
+    /// ends with an amount, `prefix` followed by each of the
+    /// [`UNIT_SUFFIXES`](#associatedconstant.UNIT_SUFFIXES), ex) "1h3" -> "1h3ns" .. "1h3y".
+    pub fn complete(prefix: &str) -> Vec<String> {
+        if prefix.ends_with(|c: char| c.is_ascii_digit()) {
+            Duration::UNIT_SUFFIXES
+                .iter()
+                .map(|suffix| format!("{}{}", prefix, suffix))
+                .collect()
+        } else {
+            Duration::VALUE_HINTS
+                .iter()
+                .filter(|hint| hint.starts_with(prefix))
+                .map(|hint| hint.to_string())
+                .collect()
+        }
+    }
+}
+
+impl FromStr for Duration {
+    type Err = Error;
+
+    /// Parses either shorthand or English prose:
+    /// * ex) "1h 59m 59s", "1h59m59s" or "1.5h", with units accepted by
+    ///   [`TimeUnitKind`](../duration/enum.TimeUnitKind.html)'s `FromStr` plus "ms", "us" and "ns"
+    /// * ex) "1 hour, 59 minutes and 59 seconds.", as written by `Display`
+    ///
+    /// Fractional amounts are carried exactly into smaller units, ex) "1.25h" is 1 hour and 15
+    /// minutes, in both forms. What remains below a whole second is truncated, ex) "1.9s" is 1
+    /// second.
+    ///
+    /// Case, unit abbreviations and separators are treated leniently, see
+    /// [`parse_with`](../parse/fn.parse_with.html) to opt out.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_with(s, Strictness::default())
+    }
+}
+
+impl TryFrom<String> for Duration {
+    type Error = Error;
+
+    /// Parses like [`FromStr`](../duration/struct.Duration.html#impl-FromStr), which makes
+    /// `Duration` usable with `#[serde(try_from = "String")]`.
+    fn try_from(s: String) -> Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+
+impl<'a> TryFrom<&'a str> for Duration {
+    type Error = Error;
+
+    fn try_from(s: &'a str) -> Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+
+/// Finds the first duration introduced by a common log keyword in `line`, ex) "took 1.234s",
+/// "elapsed=45ms" or "in 2m03s". Returns the parsed [`Duration`](../duration/struct.Duration.html)
+/// along with the byte range of the duration itself (excluding the keyword), so that callers can
+/// rewrite it in place.
+///
+/// Keywords are matched case-insensitively and may be followed by spaces, `=` or `:`. As a
+/// `Duration` counts whole seconds, sub-second parts are truncated, ex) "45ms" is zero seconds.
+pub fn find_log_duration(line: &str) -> Option<(Duration, Range<usize>)> {
+    let lower = line.to_ascii_lowercase();
+
+    (0..line.len())
+        .filter(|&i| line.is_char_boundary(i))
+        .filter(|&i| !line[..i].ends_with(|c: char| c.is_alphanumeric()))
+        .filter_map(|i| {
+            let keyword = LOG_KEYWORDS.iter().find(|k| lower[i..].starts_with(*k))?;
+            let after = i + keyword.len();
+            let sep_len = line[after..]
+                .find(|c: char| !(c == ' ' || c == '=' || c == ':'))
+                .unwrap_or(line.len() - after);
+            if sep_len == 0 {
+                return None;
+            }
+
+            let start = after + sep_len;
+            let (nanos, len) = scan_shorthand(&line[start..], &[])?;
+            if line[start + len..].starts_with(|c: char| c.is_alphanumeric()) {
+                return None;
+            }
+            let secs = usize::try_from(nanos / NANOS_PER_SEC).ok()?;
+            Some((Duration::new(secs), start..start + len))
+        })
+        .next()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::TryFrom;
+
+    use duration::Duration;
+    use error::Error;
+    use parse::{find_log_duration, parse_no_panic, parse_with, Grammar, Limits, Parser, Strictness};
+
+    #[test]
+    fn test_duration_from_str() {
+        assert!("1h 59m 59s".parse::<Duration>() == Ok(Duration::new(7199)));
+        assert!("1h59m59s".parse::<Duration>() == Ok(Duration::new(7199)));
+        assert!("1.5h".parse::<Duration>() == Ok(Duration::new(5400)));
+        assert!("1.5 hours".parse::<Duration>() == Ok(Duration::new(5400)));
+        assert!("90 seconds".parse::<Duration>() == Ok(Duration::new(90)));
+        assert!("1500ms".parse::<Duration>() == Ok(Duration::new(1)));
+        assert!("1 hour, 59 minutes and 59 seconds.".parse::<Duration>() == Ok(Duration::new(7199)));
+        assert!("".parse::<Duration>() == Err(Error::InvalidFormat));
+        assert!("1h 2x".parse::<Duration>() == Err(Error::InvalidFormat));
+    }
+
+    #[test]
+    fn test_display_round_trip() {
+        let mut denormalized = Duration::ZERO;
+        denormalized.minutes.amount = 75;
+        let mut samples = vec![Duration::ZERO, Duration::MAX, Duration::new(1), denormalized];
+        let mut state: u64 = 1;
+        for _ in 0..1000 {
+            state = state.wrapping_mul(6_364_136_223_846_793_005).wrapping_add(1);
+            samples.push(Duration::new((state >> (state % 64)) as usize));
+        }
+        for d in &samples {
+            assert!(d.to_string().parse::<Duration>() == Ok(*d));
+            assert!(parse_with(&d.to_string(), Strictness::Strict) == Ok(*d));
+        }
+    }
+
+    #[test]
+    fn test_fractional_carryover() {
+        assert!("1.25h".parse::<Duration>() == Ok(Duration::new(4500)));
+        assert!("0.5m".parse::<Duration>() == Ok(Duration::new(30)));
+        assert!("0.1y".parse::<Duration>() == Ok(Duration::new(3_153_600)));
+        assert!("1.5d 0.25h".parse::<Duration>() == Ok(Duration::new(130_500)));
+        assert!("1.9s".parse::<Duration>() == Ok(Duration::new(1)));
+        assert!("1.99999999999999999999s".parse::<Duration>() == Ok(Duration::new(1)));
+        assert!("1.h".parse::<Duration>() == Err(Error::InvalidFormat));
+    }
+
+    #[test]
+    fn test_parse_lenient() {
+        for s in &["2H", "2 hrs.", "2hours", "2 Hours", "2\u{a0}h", "1 hr; 60 mins", "1h, 30m and 30m"] {
+            assert!(s.parse::<Duration>() == Ok(Duration::new(7200)));
+        }
+        assert!("1 Hour, 59 minutes and 59 seconds".parse::<Duration>() == Ok(Duration::new(7199)));
+        assert!("2 parsecs".parse::<Duration>() == Err(Error::UnknownUnit));
+    }
+
+    #[test]
+    fn test_parse_strict() {
+        assert!(parse_with("2h", Strictness::Strict) == Ok(Duration::new(7200)));
+        assert!(parse_with("2H", Strictness::Strict) == Err(Error::InvalidFormat));
+        assert!(parse_with("2 hrs.", Strictness::Strict) == Err(Error::UnknownUnit));
+        assert!(parse_with("1h; 1m", Strictness::Strict) == Err(Error::InvalidFormat));
+    }
+
+    #[test]
+    fn test_parser_aliases() {
+        let parser = Parser::builder()
+            .alias("fortnight", Duration::new(14 * 86_400))
+            .alias("Q", Duration::new(91 * 86_400))
+            .build();
+        assert!(parser.parse("2fortnight") == Ok(Duration::new(28 * 86_400)));
+        assert!(parser.parse("1q 5d") == Ok(Duration::new(96 * 86_400)));
+        assert!(parser.parse("1 Q and 1 hour") == Ok(Duration::new(91 * 86_400 + 3600)));
+        assert!(parser.parse("0.5fortnight") == Ok(Duration::new(7 * 86_400)));
+        assert!(parser.parse("1 hour") == Ok(Duration::new(3600)));
+        assert!("2fortnight".parse::<Duration>() == Err(Error::InvalidFormat));
+
+        let strict = Parser::builder()
+            .strictness(Strictness::Strict)
+            .alias("q", Duration::new(91 * 86_400))
+            .build();
+        assert!(strict.parse("1Q") == Err(Error::InvalidFormat));
+    }
+
+    #[test]
+    fn test_parser_grammar() {
+        let v1 = Parser::builder().grammar(Grammar::V1).build();
+        assert!(v1.parse("1h 30m") == Ok(Duration::new(5400)));
+        assert!(v1.parse("1H 30M") == Ok(Duration::new(5400)));
+        assert!(v1.parse("1 hour and 30 minutes") == Err(Error::InvalidFormat));
+        assert!(v1.parse("1 Hour, 30 mins").is_err());
+        let v1 = Parser::builder().grammar(Grammar::V1).strictness(Strictness::Strict).build();
+        assert!(v1.parse("1h 30m") == Ok(Duration::new(5400)));
+        assert!(v1.parse("1 hour and 30 minutes.") == Err(Error::InvalidFormat));
+
+        let v2 = Parser::builder().build();
+        assert!(v2.parse("1 hour and 30 minutes.") == Ok(Duration::new(5400)));
+        assert!(v2.parse("1h + 30m") == Err(Error::InvalidFormat));
+
+        let v3 = Parser::builder().grammar(Grammar::V3).build();
+        assert!(v3.parse("1h + 30m") == Ok(Duration::new(5400)));
+        assert!(v3.parse("1 hour and 30 minutes.") == Ok(Duration::new(5400)));
+    }
+
+    #[test]
+    fn test_parser_limits() {
+        let parser = Parser::builder().grammar(Grammar::V3).limits(Limits::untrusted()).build();
+        assert!(parser.parse("1h 30.123456789m + 2 * 5s") == Ok(Duration::new(5417)));
+        assert!(parser.parse(&"1s ".repeat(17)) == Err(Error::LimitsExceeded));
+        assert!(parser.parse(&format!("1{}s", " ".repeat(256))) == Err(Error::LimitsExceeded));
+        assert!(parser.parse("1000000001s") == Err(Error::LimitsExceeded));
+        assert!(parser.parse(&format!("{}s", "9".repeat(40))) == Err(Error::LimitsExceeded));
+        assert!(parser.parse("1000000000s") == Ok(Duration::new(1_000_000_000)));
+        assert!(Parser::builder().build().parse(&"1s ".repeat(17)) == Ok(Duration::new(17)));
+    }
+
+    #[test]
+    fn test_parse_no_panic() {
+        assert!(parse_no_panic(b"1h 30m") == Ok(Duration::new(5400)));
+        assert!(parse_no_panic(b"1\xffh") == Err(Error::InvalidFormat));
+
+        // A fixed pseudo-random walk over bytes the parsers treat specially.
+        let alphabet = "09.,;:+-*() \u{a0}hmsdyµnuqaé".as_bytes();
+        let parser = Parser::builder()
+            .grammar(Grammar::V3)
+            .alias("q", Duration::new(usize::MAX))
+            .build();
+        let mut state: u64 = 1;
+        for _ in 0..20_000 {
+            let mut input = Vec::new();
+            for _ in 0..(state >> 60) + 1 {
+                state = state.wrapping_mul(6_364_136_223_846_793_005).wrapping_add(1);
+                input.push(alphabet[(state >> 33) as usize % alphabet.len()]);
+            }
+            let _ = parse_no_panic(&input);
+            if let Ok(s) = ::std::str::from_utf8(&input) {
+                let _ = parser.parse(s);
+                let _ = parser.parse(&s.repeat(8));
+            }
+        }
+    }
+
+    #[test]
+    fn test_value_hints() {
+        for s in Duration::VALUE_HINTS.iter().chain(Duration::UNIT_SUFFIXES) {
+            assert!(s.parse::<Duration>().is_ok() || format!("1{}", s).parse::<Duration>().is_ok());
+        }
+        assert!(Duration::complete("1h3").iter().all(|s| s.parse::<Duration>().is_ok()));
+        assert!(Duration::complete("30")[4] == "30s");
+        assert!(Duration::complete("1h") == ["1h"]);
+        assert!(Duration::complete("") == Duration::VALUE_HINTS);
+        assert!(Duration::complete("1x").is_empty());
+    }
+
+    #[test]
+    fn test_duration_try_from_string() {
+        assert!(Duration::try_from("1h 59m 59s".to_string()) == Ok(Duration::new(7199)));
+        assert!(Duration::try_from("2 days") == Ok(Duration::new(172_800)));
+        assert!(Duration::try_from(String::new()) == Err(Error::InvalidFormat));
+    }
+
+    #[test]
+    fn test_find_log_duration() {
+        let line = "GET /index.html took 1.234s";
+        let (d, span) = find_log_duration(line).unwrap();
+        assert!(d == Duration::new(1));
+        assert!(&line[span] == "1.234s");
+
+        let line = "request done elapsed=45ms status=200";
+        let (d, span) = find_log_duration(line).unwrap();
+        assert!(d == Duration::new(0));
+        assert!(span == (21..25));
+
+        let line = "Finished in 2m03s.";
+        let (d, span) = find_log_duration(line).unwrap();
+        assert!(d == Duration::new(123));
+        assert!(&line[span] == "2m03s");
+
+        let line = "duration: 1.5h";
+        assert!(find_log_duration(line).unwrap().0 == Duration::new(5400));
+    }
+
+    #[test]
+    fn test_find_log_duration_no_match() {
+        assert!(find_log_duration("logged in as admin").is_none());
+        assert!(find_log_duration("within 5s").is_none());
+        assert!(find_log_duration("took 5 seconds").is_none());
+        assert!(find_log_duration("took 5sx").is_none());
+        assert!(find_log_duration("took 100000000000000000000000s").is_none());
+    }
+}
@@ -0,0 +1,171 @@
+//! Durations which are statically known to be rounded to a granularity, ex) billing per started
+//! minute can take a [`MinutesPrecision`](type.MinutesPrecision.html) instead of trusting callers
+//! to round, and rounding to billing increments.
+
+use std::convert::TryFrom;
+use std::fmt;
+use std::marker::PhantomData;
+
+use duration::{Duration, Rounding, TimeUnitKind};
+use error::Error;
+
+/// A granularity for [`Rounded`](struct.Rounded.html).
+pub trait Precision {
+    const UNIT: TimeUnitKind;
+}
+
+/// Whole seconds, which every [`Duration`](../duration/struct.Duration.html) already is.
+pub enum Second {}
+/// Whole minutes.
+pub enum Minute {}
+/// Whole hours.
+pub enum Hour {}
+/// Whole days.
+pub enum Day {}
+
+impl Precision for Second {
+    const UNIT: TimeUnitKind = TimeUnitKind::Seconds;
+}
+
+impl Precision for Minute {
+    const UNIT: TimeUnitKind = TimeUnitKind::Minutes;
+}
+
+impl Precision for Hour {
+    const UNIT: TimeUnitKind = TimeUnitKind::Hours;
+}
+
+impl Precision for Day {
+    const UNIT: TimeUnitKind = TimeUnitKind::Days;
+}
+
+/// A [`Duration`](../duration/struct.Duration.html) which is a whole multiple of the unit of `P`.
+/// Only [`new`](#method.new) builds one, so holding it proves that rounding happened.
+pub struct Rounded<P: Precision> {
+    duration: Duration,
+    precision: PhantomData<P>,
+}
+
+pub type SecondsPrecision = Rounded<Second>;
+pub type MinutesPrecision = Rounded<Minute>;
+pub type HoursPrecision = Rounded<Hour>;
+pub type DaysPrecision = Rounded<Day>;
+
+impl<P: Precision> Rounded<P> {
+    /// Rounds `d` to a whole multiple of the unit of `P`, ex) 61 seconds rounded up to minutes
+    /// is 2 minutes. Returns [`Error::Overflow`](../error/enum.Error.html) if rounding up
+    /// exceeds the largest duration.
+    pub fn new(d: Duration, rounding: Rounding) -> Result<Self, Error> {
+        let unit = P::UNIT.as_seconds() as u128;
+        let secs = rounding.div(d.as_secs() as u128, unit) * unit;
+        let secs = usize::try_from(secs).map_err(|_| Error::Overflow)?;
+        Ok(Rounded {
+            duration: Duration::new(secs),
+            precision: PhantomData,
+        })
+    }
+
+    pub fn duration(&self) -> Duration {
+        self.duration
+    }
+
+    /// The amount of whole units of `P`, ex) 2 for 2 minutes at minutes precision.
+    pub fn units(&self) -> u64 {
+        self.duration.as_secs() / P::UNIT.as_seconds() as u64
+    }
+
+    /// Changes to a coarser or finer precision, rounding again as needed.
+    pub fn convert<Q: Precision>(&self, rounding: Rounding) -> Result<Rounded<Q>, Error> {
+        Rounded::new(self.duration, rounding)
+    }
+}
+
+impl<P: Precision> Clone for Rounded<P> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<P: Precision> Copy for Rounded<P> {}
+
+impl<P: Precision> PartialEq for Rounded<P> {
+    fn eq(&self, other: &Self) -> bool {
+        self.duration == other.duration
+    }
+}
+
+impl<P: Precision> fmt::Display for Rounded<P> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        fmt::Display::fmt(&self.duration, f)
+    }
+}
+
+impl<P: Precision> From<Rounded<P>> for Duration {
+    fn from(rounded: Rounded<P>) -> Duration {
+        rounded.duration
+    }
+}
+
+impl Duration {
+    /// Rounds `self` to the precision `P`, ex) `d.round_to::<Minute>(Rounding::Up)`.
+    pub fn round_to<P: Precision>(&self, rounding: Rounding) -> Result<Rounded<P>, Error> {
+        Rounded::new(*self, rounding)
+    }
+
+    /// Rounds `self` up to a whole multiple of `increment`, as billing per started increment
+    /// does, ex) 13 minutes in 6 minute increments is 18 minutes. Saturates at `Duration::MAX`.
+    /// A zero `increment` is an [`Error::InvalidFormat`](../error/enum.Error.html).
+    pub fn round_up_to_increment(&self, increment: Duration) -> Result<Duration, Error> {
+        let secs = self.billable_units(increment)? as u128 * increment.as_secs() as u128;
+        Ok(Duration::new(usize::try_from(secs).unwrap_or(usize::MAX)))
+    }
+
+    /// The amount of started `increment`s in `self`, ex) 3 for 13 minutes in 6 minute
+    /// increments, or 2 for parking 61 minutes per started hour. Zero bills nothing. A zero
+    /// `increment` is an [`Error::InvalidFormat`](../error/enum.Error.html).
+    pub fn billable_units(&self, increment: Duration) -> Result<u64, Error> {
+        if increment.is_zero() {
+            return Err(Error::InvalidFormat);
+        }
+        let units = Rounding::Up.div(self.as_secs() as u128, increment.as_secs() as u128);
+        u64::try_from(units).map_err(|_| Error::Overflow)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use duration::{Duration, Rounding};
+    use error::Error;
+    use precision::{Hour, Minute, MinutesPrecision, Rounded};
+
+    fn bill(time: MinutesPrecision) -> u64 {
+        time.units() * 2
+    }
+
+    #[test]
+    fn test_rounded() {
+        let d = Duration::new(61);
+        assert!(bill(MinutesPrecision::new(d, Rounding::Up).unwrap()) == 4);
+        assert!(MinutesPrecision::new(d, Rounding::Down).unwrap().duration() == Duration::new(60));
+        assert!(MinutesPrecision::new(d, Rounding::Nearest).unwrap().to_string() == "1 minute.");
+
+        let hours: Rounded<Hour> = d.round_to(Rounding::Up).unwrap();
+        assert!(hours.units() == 1);
+        assert!(hours.convert::<Minute>(Rounding::Down).unwrap().units() == 60);
+        assert!(Duration::MAX.round_to::<Hour>(Rounding::Up).err() == Some(Error::Overflow));
+    }
+
+    #[test]
+    fn test_billing_increments() {
+        let six_minutes = Duration::new(360);
+        assert!(Duration::new(13 * 60).billable_units(six_minutes) == Ok(3));
+        assert!(Duration::new(13 * 60).round_up_to_increment(six_minutes) == Ok(Duration::new(18 * 60)));
+        assert!(Duration::new(12 * 60).billable_units(six_minutes) == Ok(2));
+        assert!(Duration::new(61 * 60).billable_units(Duration::new(3600)) == Ok(2));
+        assert!(Duration::ZERO.billable_units(six_minutes) == Ok(0));
+        assert!(Duration::MAX.round_up_to_increment(six_minutes) == Ok(Duration::MAX));
+
+        assert!(Duration::new(60).billable_units(Duration::ZERO) == Err(Error::InvalidFormat));
+        assert!(Duration::new(60).round_up_to_increment(Duration::ZERO) == Err(Error::InvalidFormat));
+    }
+}
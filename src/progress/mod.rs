@@ -0,0 +1,250 @@
+use std::fmt;
+use std::time::Duration as StdDuration;
+
+use duration::{Duration, Style};
+
+/// Renders the elapsed and remaining time fields of a progress bar. Progress bar crates take
+/// an implementor to delegate field rendering to this crate's formatting.
+pub trait DurationRenderer {
+    fn render_elapsed(&self, elapsed: StdDuration) -> String;
+    fn render_eta(&self, eta: StdDuration) -> String;
+}
+
+/// A [`DurationRenderer`](trait.DurationRenderer.html) with one
+/// [`Style`](../duration/enum.Style.html) per field. Defaults to a compact elapsed time and an
+/// approximate ETA, ex) "1m 5s" and "about 3 minutes".
+#[derive(PartialEq, Clone, Copy, Debug)]
+pub struct ProgressRenderer {
+    pub elapsed: Style,
+    pub eta: Style,
+}
+
+impl Default for ProgressRenderer {
+    fn default() -> Self {
+        ProgressRenderer {
+            elapsed: Style::Compact,
+            eta: Style::Approximate,
+        }
+    }
+}
+
+impl DurationRenderer for ProgressRenderer {
+    fn render_elapsed(&self, elapsed: StdDuration) -> String {
+        Human::new(elapsed, self.elapsed).to_string()
+    }
+
+    fn render_eta(&self, eta: StdDuration) -> String {
+        Human::new(eta, self.eta).to_string()
+    }
+}
+
+/// Displays a `std::time::Duration` in a [`Style`](../duration/enum.Style.html), in the manner of
+/// indicatif's `HumanDuration`, ex) `format!("{}", Human::new(elapsed, Style::Compact))`.
+/// Durations beyond the range of a [`Duration`](../duration/struct.Duration.html) saturate.
+#[derive(PartialEq, Clone, Copy, Debug)]
+pub struct Human {
+    duration: StdDuration,
+    style: Style,
+}
+
+impl Human {
+    pub fn new(duration: StdDuration, style: Style) -> Self {
+        Human { duration, style }
+    }
+}
+
+impl fmt::Display for Human {
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        let d = Duration::try_from_std(self.duration).unwrap_or_else(|_| Duration::new(usize::MAX));
+        f.write_str(&d.format_style(self.style))
+    }
+}
+
+/// The elapsed and total time of a task, rendered as one line, ex) "12 minutes elapsed, about 3
+/// minutes remaining (80%)". Each part can be restyled or hidden.
+#[derive(PartialEq, Clone, Copy, Debug)]
+pub struct Progress {
+    elapsed: Duration,
+    total: Duration,
+    elapsed_style: Option<Style>,
+    remaining_style: Option<Style>,
+    show_percent: bool,
+}
+
+impl Progress {
+    /// Shows the elapsed time as prose, the remaining time approximately and the percentage.
+    pub fn new(elapsed: Duration, total: Duration) -> Self {
+        Progress {
+            elapsed,
+            total,
+            elapsed_style: Some(Style::Prose),
+            remaining_style: Some(Style::Approximate),
+            show_percent: true,
+        }
+    }
+
+    /// Shows the elapsed time in `style`, or hides it for `None`.
+    pub fn elapsed_style(mut self, style: Option<Style>) -> Self {
+        self.elapsed_style = style;
+        self
+    }
+
+    /// Shows the remaining time in `style`, or hides it for `None`.
+    pub fn remaining_style(mut self, style: Option<Style>) -> Self {
+        self.remaining_style = style;
+        self
+    }
+
+    pub fn show_percent(mut self, show: bool) -> Self {
+        self.show_percent = show;
+        self
+    }
+
+    /// The time left until `total`, zero once it has elapsed.
+    pub fn remaining(&self) -> Duration {
+        Duration::new(self.total.total_seconds().saturating_sub(self.elapsed.total_seconds()))
+    }
+
+    /// How much of `total` has elapsed, rounded down and capped at 100. A zero total is done.
+    pub fn percent(&self) -> u8 {
+        let total = self.total.as_secs() as u128;
+        if total == 0 {
+            return 100;
+        }
+        (self.elapsed.as_secs() as u128 * 100 / total).min(100) as u8
+    }
+}
+
+/// Formats `d` in `style` without the trailing period of prose.
+fn part(d: &Duration, style: Style) -> String {
+    d.format_style(style).trim_end_matches('.').to_string()
+}
+
+impl fmt::Display for Progress {
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        let mut parts = Vec::new();
+        if let Some(style) = self.elapsed_style {
+            parts.push(format!("{} elapsed", part(&self.elapsed, style)));
+        }
+        if let Some(style) = self.remaining_style {
+            parts.push(format!("{} remaining", part(&self.remaining(), style)));
+        }
+        let mut s = parts.join(", ");
+        if self.show_percent {
+            if !s.is_empty() {
+                s.push(' ');
+            }
+            s.push_str(&format!("({}%)", self.percent()));
+        }
+        f.write_str(&s)
+    }
+}
+
+impl Duration {
+    /// Estimates the time remaining until `total` units of work are done, given that `done` units
+    /// took `elapsed`, assuming a constant rate. Returns `None` while `done` is zero, as no rate
+    /// is known yet, and a zero duration once `done` reaches `total`.
+    pub fn eta(done: u64, total: u64, elapsed: Duration) -> Option<Duration> {
+        if done == 0 {
+            return None;
+        }
+        let remaining = u128::from(total.saturating_sub(done));
+        let secs = elapsed.total_seconds() as u128 * remaining / u128::from(done);
+        Some(Duration::new(secs.min(usize::MAX as u128) as usize))
+    }
+
+    /// Renders [`eta`](struct.Duration.html#method.eta) approximately, ex) "about 3 minutes
+    /// remaining", or "estimating time remaining" while `done` is zero.
+    pub fn format_eta(done: u64, total: u64, elapsed: Duration) -> String {
+        match Duration::eta(done, total, elapsed) {
+            Some(eta) => format!("{} remaining", eta.format_approximate()),
+            None => "estimating time remaining".to_string(),
+        }
+    }
+
+    /// The time left to transfer `bytes_remaining` at `bytes_per_sec`, rounded up to whole
+    /// seconds and saturating. Returns `None` for rates below 1 byte per second, which are
+    /// treated as stalled, including zero, negative and NaN rates.
+    pub fn transfer_remaining(bytes_remaining: u64, bytes_per_sec: f64) -> Option<Duration> {
+        if bytes_per_sec.is_nan() || bytes_per_sec < 1.0 {
+            return None;
+        }
+        // Float to integer casts saturate.
+        Some(Duration::new((bytes_remaining as f64 / bytes_per_sec).ceil() as usize))
+    }
+
+    /// Renders [`transfer_remaining`](struct.Duration.html#method.transfer_remaining)
+    /// approximately, ex) "about 3 minutes remaining", or "stalled" when the rate is too low to
+    /// estimate.
+    pub fn transfer_eta(bytes_remaining: u64, bytes_per_sec: f64) -> String {
+        match Duration::transfer_remaining(bytes_remaining, bytes_per_sec) {
+            Some(eta) => format!("{} remaining", eta.format_approximate()),
+            None => "stalled".to_string(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration as StdDuration;
+
+    use duration::{Duration, Style};
+    use progress::{DurationRenderer, Human, Progress, ProgressRenderer};
+
+    #[test]
+    fn test_eta() {
+        assert!(Duration::eta(25, 100, Duration::new(60)) == Some(Duration::new(180)));
+        assert!(Duration::eta(100, 100, Duration::new(60)) == Some(Duration::new(0)));
+        assert!(Duration::eta(150, 100, Duration::new(60)) == Some(Duration::new(0)));
+        assert!(Duration::eta(0, 100, Duration::new(60)).is_none());
+    }
+
+    #[test]
+    fn test_format_eta() {
+        assert!(Duration::format_eta(40, 100, Duration::new(115)) == "about 3 minutes remaining");
+        assert!(Duration::format_eta(0, 100, Duration::new(5)) == "estimating time remaining");
+    }
+
+    #[test]
+    fn test_transfer_eta() {
+        assert!(Duration::transfer_remaining(10_000_000, 1_000_000.0) == Some(Duration::new(10)));
+        assert!(Duration::transfer_remaining(1, 1_000_000.0) == Some(Duration::new(1)));
+        assert!(Duration::transfer_remaining(u64::MAX, 1.0) == Some(Duration::new(u64::MAX as usize)));
+        assert!(Duration::transfer_eta(170_000_000, 1_000_000.0) == "about 3 minutes remaining");
+        assert!(Duration::transfer_eta(0, 1_000_000.0) == "less than a second remaining");
+        assert!(Duration::transfer_eta(1_000, 0.5) == "stalled");
+        assert!(Duration::transfer_eta(1_000, 0.0) == "stalled");
+        assert!(Duration::transfer_eta(1_000, f64::NAN) == "stalled");
+    }
+
+    #[test]
+    fn test_progress_renderer() {
+        let renderer = ProgressRenderer::default();
+        assert!(renderer.render_elapsed(StdDuration::from_secs(65)) == "1m 5s");
+        assert!(renderer.render_eta(StdDuration::from_secs(170)) == "about 3 minutes");
+
+        let renderer = ProgressRenderer { eta: Style::Compact, ..renderer };
+        assert!(renderer.render_eta(StdDuration::from_millis(170_500)) == "2m 50s");
+    }
+
+    #[test]
+    fn test_human_display() {
+        let human = Human::new(StdDuration::from_secs(3600), Style::Prose);
+        assert!(format!("{}", human) == "1 hour.");
+    }
+
+    #[test]
+    fn test_progress() {
+        let progress = Progress::new(Duration::new(12 * 60), Duration::new(15 * 60));
+        assert!(progress.to_string() == "12 minutes elapsed, about 3 minutes remaining (80%)");
+        assert!(progress.remaining() == Duration::new(180));
+
+        let progress = progress.elapsed_style(Some(Style::Compact)).remaining_style(None);
+        assert!(progress.to_string() == "12m elapsed (80%)");
+        assert!(progress.show_percent(false).to_string() == "12m elapsed");
+
+        let overrun = Progress::new(Duration::new(20 * 60), Duration::new(15 * 60));
+        assert!(overrun.percent() == 100 && overrun.remaining().is_zero());
+        assert!(Progress::new(Duration::ZERO, Duration::ZERO).percent() == 100);
+    }
+}
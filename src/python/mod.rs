@@ -0,0 +1,113 @@
+//! Python bindings, behind the `pyo3` feature. A [`Duration`](../duration/struct.Duration.html)
+//! converts to and from `datetime.timedelta`, and [`PyDuration`](struct.PyDuration.html) exposes
+//! the crate's formatting to Python.
+
+use std::convert::TryFrom;
+
+use pyo3::exceptions::{PyOverflowError, PyValueError};
+use pyo3::prelude::*;
+use pyo3::types::{PyDelta, PyDeltaAccess};
+
+use duration::Duration;
+use error::Error;
+
+impl From<Error> for PyErr {
+    fn from(e: Error) -> PyErr {
+        match e {
+            Error::Overflow => PyOverflowError::new_err(e.to_string()),
+            _ => PyValueError::new_err(e.to_string()),
+        }
+    }
+}
+
+impl<'py> IntoPyObject<'py> for Duration {
+    type Target = PyDelta;
+    type Output = Bound<'py, PyDelta>;
+    type Error = PyErr;
+
+    /// Converts to a `datetime.timedelta` of the same amount of seconds.
+    fn into_pyobject(self, py: Python<'py>) -> PyResult<Bound<'py, PyDelta>> {
+        let secs = self.try_into_std()?.as_secs();
+        let days = i32::try_from(secs / 86_400).map_err(|_| Error::Overflow)?;
+        PyDelta::new(py, days, (secs % 86_400) as i32, 0, false)
+    }
+}
+
+impl<'a, 'py> FromPyObject<'a, 'py> for Duration {
+    type Error = PyErr;
+
+    /// Converts from a non-negative `datetime.timedelta`, truncating microseconds.
+    fn extract(obj: Borrowed<'a, 'py, PyAny>) -> PyResult<Self> {
+        let delta = obj.cast::<PyDelta>()?;
+        let days = usize::try_from(delta.get_days())
+            .map_err(|_| PyValueError::new_err("negative timedelta"))?;
+        let secs = days
+            .checked_mul(86_400)
+            .and_then(|secs| secs.checked_add(delta.get_seconds() as usize))
+            .ok_or(Error::Overflow)?;
+        Ok(Duration::new(secs))
+    }
+}
+
+/// A [`Duration`](../duration/struct.Duration.html) exposed to Python as `Duration`, whose
+/// `str()` is the crate's prose `Display`.
+#[pyclass(name = "Duration", frozen)]
+#[derive(Clone)]
+pub struct PyDuration(pub Duration);
+
+#[pymethods]
+impl PyDuration {
+    #[new]
+    fn new(seconds: usize) -> Self {
+        PyDuration(Duration::new(seconds))
+    }
+
+    /// Parses any format accepted by the crate's `FromStr`.
+    #[staticmethod]
+    fn parse(s: &str) -> PyResult<Self> {
+        Ok(PyDuration(s.parse()?))
+    }
+
+    #[staticmethod]
+    fn from_timedelta(delta: Duration) -> Self {
+        PyDuration(delta)
+    }
+
+    fn to_timedelta(&self) -> Duration {
+        self.0
+    }
+
+    fn compact(&self) -> String {
+        self.0.format_compact()
+    }
+
+    fn __str__(&self) -> String {
+        self.0.to_string()
+    }
+
+    fn __repr__(&self) -> String {
+        format!("Duration({})", self.0.format_compact())
+    }
+}
+
+/// Adds [`PyDuration`](struct.PyDuration.html) to the Python module `m`.
+pub fn register(m: &Bound<PyModule>) -> PyResult<()> {
+    m.add_class::<PyDuration>()
+}
+
+#[cfg(test)]
+mod tests {
+    use pyo3::prelude::*;
+
+    use duration::Duration;
+
+    #[test]
+    fn test_timedelta_round_trip() {
+        Python::initialize();
+        Python::attach(|py| {
+            let delta = Duration::new(93_784).into_pyobject(py).unwrap();
+            assert!(delta.str().unwrap() == "1 day, 2:03:04");
+            assert!(delta.extract::<Duration>().unwrap() == Duration::new(93_784));
+        });
+    }
+}
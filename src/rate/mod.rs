@@ -0,0 +1,206 @@
+//! Rates of events over time, ex) "1 request every 2.5 seconds" or "24 builds per hour".
+
+use std::fmt;
+use std::str::FromStr;
+
+use duration::{Duration, TimeUnitKind, KINDS};
+use error::Error;
+
+/// A count of events over a [`Duration`](../duration/struct.Duration.html), named by a
+/// caller-supplied noun.
+#[derive(PartialEq, Clone, Copy)]
+pub struct Rate<'a> {
+    pub events: u64,
+    pub duration: Duration,
+    singular: &'a str,
+    plural: &'a str,
+}
+
+/// Formats `amount` with at most one decimal, ex) 2.5 or 24.
+fn decimal(amount: f64) -> String {
+    let s = format!("{:.1}", amount);
+    s.trim_end_matches(".0").to_string()
+}
+
+impl<'a> Rate<'a> {
+    /// Counts events as "event" and "events" until relabelled.
+    pub fn new(events: u64, duration: Duration) -> Self {
+        Rate {
+            events,
+            duration,
+            singular: "event",
+            plural: "events",
+        }
+    }
+
+    /// Names the events, ex) `label("build", "builds")`.
+    pub fn label(mut self, singular: &'a str, plural: &'a str) -> Self {
+        self.singular = singular;
+        self.plural = plural;
+        self
+    }
+
+    fn noun(&self, amount: &str) -> &'a str {
+        if amount == "1" {
+            self.singular
+        } else {
+            self.plural
+        }
+    }
+
+    /// Words for the rates no division describes: "no requests" without events, and "3 requests
+    /// at once" for events in no time at all.
+    fn degenerate(&self) -> Option<String> {
+        if self.events == 0 {
+            Some(format!("no {}", self.plural))
+        } else if self.duration.is_zero() {
+            let amount = self.events.to_string();
+            Some(format!("{} {} at once", amount, self.noun(&amount)))
+        } else {
+            None
+        }
+    }
+
+    /// The events per one `unit`, ex) "24 builds per hour". Worded like `Display` without events
+    /// or without time.
+    pub fn per(&self, unit: TimeUnitKind) -> String {
+        if let Some(s) = self.degenerate() {
+            return s;
+        }
+        let secs = self.duration.total_seconds() as f64;
+        let amount = decimal(self.events as f64 * unit.as_seconds() as f64 / secs);
+        format!("{} {} per {}", amount, self.noun(&amount), unit)
+    }
+
+    /// The time between two events in the largest unit it spans, ex) "1 request every 2.5
+    /// seconds" or "1 build every minute". Worded like `Display` without events or without time.
+    pub fn every(&self) -> String {
+        if let Some(s) = self.degenerate() {
+            return s;
+        }
+        let period = self.duration.total_seconds() as f64 / self.events as f64;
+        let unit = KINDS
+            .iter()
+            .rev()
+            .find(|kind| period >= kind.as_seconds() as f64)
+            .cloned()
+            .unwrap_or(TimeUnitKind::Seconds);
+        let amount = decimal(period / unit.as_seconds() as f64);
+        if amount == "1" {
+            format!("1 {} every {}", self.singular, unit)
+        } else {
+            format!("1 {} every {} {}", self.singular, amount, unit.plural_name())
+        }
+    }
+
+    /// Describes `self` as a rate-limit window, ex) "100 requests per 15 minutes" or, for a
+    /// window of exactly one unit, "100 requests per minute".
+    pub fn window(&self) -> String {
+        let amount = self.events.to_string();
+        let mut units = self.duration.iter_units();
+        let window = match (units.next(), units.next()) {
+            (Some(unit), None) if unit.amount == 1 => unit.kind.to_string(),
+            _ => self.duration.to_string().trim_end_matches('.').to_string(),
+        };
+        format!("{} {} per {}", amount, self.noun(&amount), window)
+    }
+}
+
+impl<'a> FromStr for Rate<'a> {
+    type Err = Error;
+
+    /// Parses the limit notation of gateway configs, ex) "100/15m", or "100/m" for a window of
+    /// one unit. A zero window, ex) "100/0s", is an `Error::InvalidFormat`.
+    fn from_str(s: &str) -> Result<Self, Error> {
+        let (events, window) = s.split_once('/').ok_or(Error::InvalidFormat)?;
+        let events = events.trim();
+        if events.is_empty() || !events.chars().all(|c| c.is_ascii_digit()) {
+            return Err(Error::InvalidFormat);
+        }
+        let events = events.parse().map_err(|_| Error::Overflow)?;
+        let window = window.trim();
+        let duration: Duration = if window.starts_with(|c: char| c.is_ascii_digit()) {
+            window.parse()?
+        } else {
+            format!("1{}", window).parse()?
+        };
+        if duration.is_zero() {
+            return Err(Error::InvalidFormat);
+        }
+        Ok(Rate::new(events, duration))
+    }
+}
+
+impl<'a> fmt::Display for Rate<'a> {
+    /// Formats as [`every`](#method.every) while events are at least a second apart, and as
+    /// [`per`](#method.per) second otherwise, ex) "12 requests per second". Without events,
+    /// formats as "no requests", and events in no time at all as "3 requests at once".
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if let Some(s) = self.degenerate() {
+            f.write_str(&s)
+        } else if self.duration.total_seconds() as u64 >= self.events {
+            f.write_str(&self.every())
+        } else {
+            f.write_str(&self.per(TimeUnitKind::Seconds))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use duration::{Duration, TimeUnitKind};
+    use error::Error;
+    use rate::Rate;
+
+    #[test]
+    fn test_rate_every() {
+        let rate = Rate::new(4, Duration::new(10)).label("request", "requests");
+        assert!(rate.to_string() == "1 request every 2.5 seconds");
+        assert!(Rate::new(24, Duration::new(1440)).every() == "1 event every minute");
+        assert!(Rate::new(2, Duration::new(5 * 86_400)).every() == "1 event every 2.5 days");
+    }
+
+    #[test]
+    fn test_rate_per() {
+        let rate = Rate::new(24, Duration::new(3600)).label("build", "builds");
+        assert!(rate.per(TimeUnitKind::Hours) == "24 builds per hour");
+        assert!(rate.per(TimeUnitKind::Minutes) == "0.4 builds per minute");
+        assert!(Rate::new(1, Duration::new(60)).per(TimeUnitKind::Minutes) == "1 event per minute");
+        assert!(Rate::new(120, Duration::new(10)).to_string() == "12 events per second");
+    }
+
+    #[test]
+    fn test_rate_edge_cases() {
+        let rate = Rate::new(0, Duration::new(60)).label("request", "requests");
+        assert!(rate.to_string() == "no requests");
+        assert!(Rate::new(3, Duration::new(0)).to_string() == "3 events at once");
+
+        assert!(rate.every() == "no requests" && rate.per(TimeUnitKind::Hours) == "no requests");
+        let at_once = Rate::new(5, Duration::ZERO);
+        assert!(at_once.per(TimeUnitKind::Hours) == "5 events at once");
+        assert!(at_once.every() == "5 events at once");
+        assert!(Rate::new(1, Duration::ZERO).every() == "1 event at once");
+    }
+
+    #[test]
+    fn test_rate_window() {
+        let rate = Rate::new(100, Duration::new(900)).label("request", "requests");
+        assert!(rate.window() == "100 requests per 15 minutes");
+        assert!(Rate::new(1, Duration::new(60)).window() == "1 event per minute");
+        assert!(Rate::new(5, Duration::new(5400)).window() == "5 events per 1 hour and 30 minutes");
+    }
+
+    #[test]
+    fn test_rate_from_str() {
+        let rate = "100/15m".parse::<Rate>().unwrap().label("request", "requests");
+        assert!(rate.events == 100 && rate.duration == Duration::new(900));
+        assert!(rate.window() == "100 requests per 15 minutes");
+        assert!("10 / h".parse::<Rate>().unwrap().duration == Duration::new(3600));
+        assert!("100".parse::<Rate>() == Err(Error::InvalidFormat));
+        assert!("-1/m".parse::<Rate>() == Err(Error::InvalidFormat));
+        assert!("100/15x".parse::<Rate>().is_err());
+        assert!("100/0s".parse::<Rate>() == Err(Error::InvalidFormat));
+        assert!("100/500ms".parse::<Rate>() == Err(Error::InvalidFormat));
+        assert!("99999999999999999999/m".parse::<Rate>() == Err(Error::Overflow));
+    }
+}
@@ -0,0 +1,115 @@
+//! Ultra-compact relative labels in a single unit, as feeds show them, ex) "just now", "3d" or
+//! "2mo".
+
+use duration::Duration;
+
+const MINUTE: usize = 60;
+const HOUR: usize = 60 * MINUTE;
+const DAY: usize = 24 * HOUR;
+
+/// One unit of [`ShortLabels`](struct.ShortLabels.html).
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub struct ShortUnit {
+    pub suffix: &'static str,
+    /// The length of the unit in seconds.
+    pub length: usize,
+    /// The duration in seconds from which labels use this unit, ex) 14 days to keep counting
+    /// days for two weeks. Defaults to `length`.
+    pub from: usize,
+}
+
+const fn unit(suffix: &'static str, length: usize) -> ShortUnit {
+    ShortUnit { suffix, length, from: length }
+}
+
+/// Labels durations with the largest unit which they reach, rounded down, ex) 3 days and 20
+/// hours -> "3d". Units are seconds, minutes, hours, days, weeks, months of 30 days and years of
+/// 365 days: "s", "m", "h", "d", "w", "mo" and "y".
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub struct ShortLabels {
+    /// Durations below this are labelled "just now". Defaults to 10 seconds.
+    pub just_now: usize,
+    /// Ordered from the smallest to the largest.
+    pub units: Vec<ShortUnit>,
+}
+
+impl Default for ShortLabels {
+    fn default() -> Self {
+        ShortLabels {
+            just_now: 10,
+            units: vec![
+                unit("s", 1),
+                unit("m", MINUTE),
+                unit("h", HOUR),
+                unit("d", DAY),
+                unit("w", 7 * DAY),
+                unit("mo", 30 * DAY),
+                unit("y", 365 * DAY),
+            ],
+        }
+    }
+}
+
+impl ShortLabels {
+    /// Labels durations below `threshold` as "just now".
+    pub fn just_now(mut self, threshold: Duration) -> Self {
+        self.just_now = threshold.total_seconds();
+        self
+    }
+
+    /// Starts using the unit with `suffix` from `threshold` on, ex) `threshold("w", 14 days)`.
+    /// Unknown suffixes are ignored.
+    pub fn threshold(mut self, suffix: &str, threshold: Duration) -> Self {
+        for unit in self.units.iter_mut().filter(|unit| unit.suffix == suffix) {
+            unit.from = threshold.total_seconds();
+        }
+        self
+    }
+
+    pub fn label(&self, d: &Duration) -> String {
+        let secs = d.total_seconds();
+        if secs < self.just_now {
+            return "just now".to_string();
+        }
+        match self.units.iter().rev().find(|unit| secs >= unit.from && unit.length > 0) {
+            Some(unit) => format!("{}{}", secs / unit.length, unit.suffix),
+            None => "just now".to_string(),
+        }
+    }
+}
+
+impl Duration {
+    /// Labels `self` with the default [`ShortLabels`](../relative/struct.ShortLabels.html), ex)
+    /// "3d" or "2mo".
+    pub fn format_short(&self) -> String {
+        ShortLabels::default().label(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use duration::Duration;
+    use relative::ShortLabels;
+
+    #[test]
+    fn test_format_short() {
+        assert!(Duration::new(5).format_short() == "just now");
+        assert!(Duration::new(45).format_short() == "45s");
+        assert!(Duration::new(3599).format_short() == "59m");
+        assert!(Duration::new(3 * 86_400 + 20 * 3600).format_short() == "3d");
+        assert!(Duration::new(13 * 86_400).format_short() == "1w");
+        assert!(Duration::new(65 * 86_400).format_short() == "2mo");
+        assert!(Duration::new(800 * 86_400).format_short() == "2y");
+    }
+
+    #[test]
+    fn test_short_label_thresholds() {
+        let labels = ShortLabels::default()
+            .just_now(Duration::new(60))
+            .threshold("w", Duration::new(14 * 86_400));
+        assert!(labels.label(&Duration::new(59)) == "just now");
+        assert!(labels.label(&Duration::new(60)) == "1m");
+        assert!(labels.label(&Duration::new(13 * 86_400)) == "13d");
+        assert!(labels.label(&Duration::new(14 * 86_400)) == "2w");
+    }
+}
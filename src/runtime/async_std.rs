@@ -0,0 +1,52 @@
+use std::future::Future;
+use std::pin::Pin;
+
+use async_std::future::{self, TimeoutError};
+use async_std::task;
+
+use duration::Duration;
+use runtime::saturating_std;
+
+type BoxFuture<'a, T> = Pin<Box<dyn Future<Output = T> + Send + 'a>>;
+
+/// Sleep and timeout on the `async-std` runtime.
+pub trait AsyncStdExt {
+    /// Waits until `self` has elapsed, ex) `Duration::new(5).sleep().await`.
+    fn sleep(&self) -> BoxFuture<'static, ()>;
+
+    /// Requires `future` to complete before `self` has elapsed, resolving to
+    /// `Err(TimeoutError)` otherwise.
+    fn timeout<'a, F>(&self, future: F) -> BoxFuture<'a, Result<F::Output, TimeoutError>>
+    where
+        F: Future + Send + 'a;
+}
+
+impl AsyncStdExt for Duration {
+    fn sleep(&self) -> BoxFuture<'static, ()> {
+        Box::pin(task::sleep(saturating_std(self)))
+    }
+
+    fn timeout<'a, F>(&self, future: F) -> BoxFuture<'a, Result<F::Output, TimeoutError>>
+    where
+        F: Future + Send + 'a,
+    {
+        Box::pin(future::timeout(saturating_std(self), future))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::future;
+
+    use async_std::task;
+
+    use duration::Duration;
+    use runtime::AsyncStdExt;
+
+    #[test]
+    fn test_async_std_sleep_and_timeout() {
+        task::block_on(Duration::new(0).sleep());
+        assert!(task::block_on(Duration::new(1).timeout(future::ready(7))) == Ok(7));
+        assert!(task::block_on(Duration::new(0).timeout(Duration::new(2).sleep())).is_err());
+    }
+}
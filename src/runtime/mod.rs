@@ -0,0 +1,25 @@
+//! Sleep and timeout adapters for async runtimes, so that a timeout read from configuration can
+//! be awaited directly, ex) `"30s".parse::<Duration>()?.sleep().await`. Each runtime gets its own
+//! extension trait, behind the `tokio` and `async-std` features respectively. Blocking sleep on
+//! the current thread is always available through `ThreadExt`.
+
+use std::time::Duration as StdDuration;
+
+use duration::Duration;
+
+#[cfg(feature = "async-std")]
+mod async_std;
+mod thread;
+#[cfg(feature = "tokio")]
+mod tokio;
+
+#[cfg(feature = "async-std")]
+pub use self::async_std::AsyncStdExt;
+pub use self::thread::ThreadExt;
+#[cfg(feature = "tokio")]
+pub use self::tokio::TokioExt;
+
+/// Durations beyond `std::time::Duration` wait forever rather than fail.
+fn saturating_std(d: &Duration) -> StdDuration {
+    d.try_into_std().unwrap_or(StdDuration::MAX)
+}
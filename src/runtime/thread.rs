@@ -0,0 +1,48 @@
+use std::thread;
+use std::time::SystemTime;
+
+use duration::Duration;
+use runtime::saturating_std;
+
+/// Blocking sleep on the current thread, ex) `"90s".parse::<Duration>()?.sleep_blocking()` in a
+/// script or a test.
+pub trait ThreadExt {
+    /// Blocks the current thread until `self` has elapsed.
+    fn sleep_blocking(&self);
+}
+
+impl ThreadExt for Duration {
+    fn sleep_blocking(&self) {
+        thread::sleep(saturating_std(self))
+    }
+}
+
+impl Duration {
+    /// Blocks the current thread until the wall clock reaches `deadline`, returning at once if it
+    /// already has.
+    pub fn sleep_until(deadline: SystemTime) {
+        if let Ok(left) = deadline.duration_since(SystemTime::now()) {
+            thread::sleep(left);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::{Duration as StdDuration, Instant, SystemTime};
+
+    use duration::Duration;
+    use runtime::ThreadExt;
+
+    #[test]
+    fn test_sleep_blocking() {
+        let start = Instant::now();
+        Duration::ZERO.sleep_blocking();
+        Duration::sleep_until(SystemTime::now() - StdDuration::from_secs(60));
+        assert!(start.elapsed() < StdDuration::from_secs(1));
+
+        let start = Instant::now();
+        Duration::sleep_until(SystemTime::now() + StdDuration::from_millis(20));
+        assert!(start.elapsed() >= StdDuration::from_millis(20));
+    }
+}
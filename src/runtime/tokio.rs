@@ -0,0 +1,46 @@
+use std::future::Future;
+
+use tokio::time::{self, Sleep, Timeout};
+
+use duration::Duration;
+use runtime::saturating_std;
+
+/// Sleep and timeout on the `tokio` runtime.
+pub trait TokioExt {
+    /// Waits until `self` has elapsed, ex) `Duration::new(5).sleep().await`.
+    fn sleep(&self) -> Sleep;
+
+    /// Requires `future` to complete before `self` has elapsed, resolving to `Err(Elapsed)`
+    /// otherwise.
+    fn timeout<F: Future>(&self, future: F) -> Timeout<F>;
+}
+
+impl TokioExt for Duration {
+    fn sleep(&self) -> Sleep {
+        time::sleep(saturating_std(self))
+    }
+
+    fn timeout<F: Future>(&self, future: F) -> Timeout<F> {
+        time::timeout(saturating_std(self), future)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::future;
+
+    use tokio::runtime::Builder;
+
+    use duration::Duration;
+    use runtime::TokioExt;
+
+    #[test]
+    fn test_tokio_sleep_and_timeout() {
+        let rt = Builder::new_current_thread().enable_time().start_paused(true).build().unwrap();
+        let _guard = rt.enter();
+        rt.block_on(Duration::new(3600).sleep());
+        assert!(rt.block_on(Duration::new(1).timeout(future::ready(7))) == Ok(7));
+        assert!(rt.block_on(Duration::MAX.timeout(future::ready(7))) == Ok(7));
+        assert!(rt.block_on(Duration::new(1).timeout(Duration::new(2).sleep())).is_err());
+    }
+}
@@ -0,0 +1,189 @@
+//! Serde support, behind the `serde` feature.
+//!
+//! Deserialization is liberal: it accepts an integer amount of seconds, a float amount of
+//! seconds (sub-second parts are truncated) or a string in any format the
+//! [`FromStr`](../duration/struct.Duration.html#impl-FromStr) implementation reads. This lets
+//! config files keep working as their duration fields evolve from `30` to `"30s"`.
+//!
+//! Serialization defaults to the compact string, ex) "1h 59m 59s". The submodules pick another
+//! representation for a field with `#[serde(with = "duration_string::serde::seconds")]`.
+
+use std::fmt;
+
+use serde_crate::de::{self, Visitor};
+use serde_crate::{Deserialize, Deserializer, Serialize, Serializer};
+
+use duration::Duration;
+
+struct DurationVisitor;
+
+impl<'de> Visitor<'de> for DurationVisitor {
+    type Value = Duration;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("an amount of seconds or a duration string")
+    }
+
+    fn visit_u64<E: de::Error>(self, v: u64) -> Result<Duration, E> {
+        if v > usize::MAX as u64 {
+            return Err(E::invalid_value(de::Unexpected::Unsigned(v), &self));
+        }
+        Ok(Duration::new(v as usize))
+    }
+
+    fn visit_i64<E: de::Error>(self, v: i64) -> Result<Duration, E> {
+        if v < 0 {
+            return Err(E::invalid_value(de::Unexpected::Signed(v), &self));
+        }
+        self.visit_u64(v as u64)
+    }
+
+    fn visit_f64<E: de::Error>(self, v: f64) -> Result<Duration, E> {
+        if v.is_nan() || v < 0.0 || v >= usize::MAX as f64 {
+            return Err(E::invalid_value(de::Unexpected::Float(v), &self));
+        }
+        Ok(Duration::new(v as usize))
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<Duration, E> {
+        v.parse().map_err(E::custom)
+    }
+}
+
+impl Serialize for Duration {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        compact::serialize(self, serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for Duration {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_any(DurationVisitor)
+    }
+}
+
+/// Serializes as an integer amount of seconds, ex) `7199`.
+pub mod seconds {
+    use serde_crate::{Deserialize, Deserializer, Serializer};
+
+    use duration::Duration;
+
+    pub fn serialize<S: Serializer>(d: &Duration, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_u64(d.total_seconds() as u64)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Duration, D::Error> {
+        Duration::deserialize(deserializer)
+    }
+}
+
+/// Serializes as the compact string, ex) "1h 59m 59s".
+pub mod compact {
+    use serde_crate::{Deserialize, Deserializer, Serializer};
+
+    use duration::Duration;
+
+    pub fn serialize<S: Serializer>(d: &Duration, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&d.format_compact())
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Duration, D::Error> {
+        Duration::deserialize(deserializer)
+    }
+}
+
+/// Serializes as the `Display` prose, ex) "1 hour, 59 minutes and 59 seconds.".
+pub mod prose {
+    use serde_crate::{Deserialize, Deserializer, Serializer};
+
+    use duration::Duration;
+
+    pub fn serialize<S: Serializer>(d: &Duration, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(d)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Duration, D::Error> {
+        Duration::deserialize(deserializer)
+    }
+}
+
+/// Serializes as a string which the crate's own parser is guaranteed to read back as an equal
+/// [`Duration`](../../duration/struct.Duration.html), for files which are written by machines
+/// and edited by humans. The string is the compact form of the normalized duration, ex) 75
+/// minutes set by hand are written as "1h 15m". Durations whose total amount of seconds does not
+/// fit in a `usize` cannot be read back and fail to serialize.
+pub mod round_trip {
+    use serde_crate::ser::Error;
+    use serde_crate::{Deserialize, Deserializer, Serializer};
+
+    use duration::Duration;
+
+    pub fn serialize<S: Serializer>(d: &Duration, serializer: S) -> Result<S::Ok, S::Error> {
+        let secs = d
+            .checked_total_seconds()
+            .ok_or_else(|| S::Error::custom("duration exceeds the largest readable duration"))?;
+        serializer.serialize_str(&Duration::new(secs).format_compact())
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Duration, D::Error> {
+        Duration::deserialize(deserializer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json;
+
+    use duration::Duration;
+
+    #[test]
+    fn test_deserialize_liberal() {
+        let from = |json: &str| serde_json::from_str::<Duration>(json).ok();
+        assert!(from("7199") == Some(Duration::new(7199)));
+        assert!(from("7199.9") == Some(Duration::new(7199)));
+        assert!(from("\"1h 59m 59s\"") == Some(Duration::new(7199)));
+        assert!(from("\"1 hour, 59 minutes and 59 seconds.\"") == Some(Duration::new(7199)));
+        assert!(from("-1").is_none());
+        assert!(from("\"soon\"").is_none());
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let round_trip = |d: &Duration| {
+            let mut json = Vec::new();
+            super::round_trip::serialize(d, &mut serde_json::Serializer::new(&mut json)).map(|_| json)
+        };
+
+        let mut denormalized = Duration::ZERO;
+        denormalized.minutes.amount = 75;
+        let mut samples = vec![Duration::ZERO, Duration::MAX, denormalized];
+        let mut state: u64 = 1;
+        for _ in 0..1000 {
+            state = state.wrapping_mul(6_364_136_223_846_793_005).wrapping_add(1);
+            samples.push(Duration::new((state >> (state % 64)) as usize));
+        }
+        for d in &samples {
+            let json = round_trip(d).unwrap();
+            assert!(serde_json::from_slice::<Duration>(&json).ok() == Some(*d));
+        }
+        assert!(round_trip(&denormalized).unwrap() == b"\"1h 15m\"");
+
+        let mut huge = Duration::ZERO;
+        huge.years.amount = usize::MAX;
+        assert!(round_trip(&huge).is_err());
+    }
+
+    #[test]
+    fn test_serialize_modes() {
+        let d = Duration::new(7199);
+        assert!(serde_json::to_string(&d).unwrap() == "\"1h 59m 59s\"");
+
+        let mut json = Vec::new();
+        super::seconds::serialize(&d, &mut serde_json::Serializer::new(&mut json)).unwrap();
+        assert!(json == b"7199");
+
+        let mut json = Vec::new();
+        super::prose::serialize(&d, &mut serde_json::Serializer::new(&mut json)).unwrap();
+        assert!(json == b"\"1 hour, 59 minutes and 59 seconds.\"");
+    }
+}
@@ -0,0 +1,130 @@
+//! Splitting of a span of time into work sessions with breaks in between, ex) for pomodoro
+//! timers.
+
+use std::cmp;
+use std::fmt;
+
+use duration::Duration;
+
+#[derive(PartialEq, Clone, Copy, Debug)]
+pub enum SegmentKind {
+    Work,
+    Break,
+}
+
+/// One work session or break of [`Segments`](struct.Segments.html).
+#[derive(PartialEq, Clone, Copy, Debug)]
+pub struct Segment {
+    pub kind: SegmentKind,
+    /// From the start of the first session.
+    pub start: Duration,
+    pub duration: Duration,
+}
+
+impl Segment {
+    /// Labels the segment as prose, ex) "work: 25 minutes" or "break: 5 minutes".
+    pub fn label(&self) -> String {
+        self.to_string()
+    }
+}
+
+impl fmt::Display for Segment {
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        let kind = match self.kind {
+            SegmentKind::Work => "work",
+            SegmentKind::Break => "break",
+        };
+        write!(f, "{}: {}", kind, self.duration.to_string().trim_end_matches('.'))
+    }
+}
+
+/// Iterates over work sessions of `chunk` with breaks of `break_between` in between until the
+/// total work amounts to the split duration. The last session is shorter when the duration is
+/// not a whole multiple of `chunk`, and no break follows it. A zero `chunk` yields nothing, a
+/// zero break yields sessions back to back.
+#[derive(Clone)]
+pub struct Segments {
+    work_left: usize,
+    chunk: usize,
+    pause: usize,
+    at: usize,
+    next_kind: SegmentKind,
+}
+
+impl Iterator for Segments {
+    type Item = Segment;
+
+    fn next(&mut self) -> Option<Segment> {
+        if self.work_left == 0 || self.chunk == 0 {
+            return None;
+        }
+        let (kind, secs) = match self.next_kind {
+            SegmentKind::Work => {
+                let secs = cmp::min(self.chunk, self.work_left);
+                self.work_left -= secs;
+                (SegmentKind::Work, secs)
+            }
+            SegmentKind::Break => (SegmentKind::Break, self.pause),
+        };
+        self.next_kind = match kind {
+            SegmentKind::Work if self.pause > 0 => SegmentKind::Break,
+            _ => SegmentKind::Work,
+        };
+
+        let segment = Segment {
+            kind,
+            start: Duration::new(self.at),
+            duration: Duration::new(secs),
+        };
+        self.at = self.at.saturating_add(secs);
+        Some(segment)
+    }
+}
+
+impl Duration {
+    /// Splits `self` worth of work into sessions of `chunk` with `break_between` in between, ex)
+    /// 1 hour in chunks of 25 minutes with 5 minute breaks is 25, 5, 25, 5 and 10 minutes.
+    pub fn split_into(&self, chunk: Duration, break_between: Duration) -> Segments {
+        Segments {
+            work_left: self.total_seconds(),
+            chunk: chunk.total_seconds(),
+            pause: break_between.total_seconds(),
+            at: 0,
+            next_kind: SegmentKind::Work,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use duration::Duration;
+    use session::{Segment, SegmentKind};
+
+    #[test]
+    fn test_split_into() {
+        let segments = Duration::new(3600)
+            .split_into(Duration::new(25 * 60), Duration::new(5 * 60))
+            .collect::<Vec<Segment>>();
+        let labels = segments.iter().map(Segment::label).collect::<Vec<String>>();
+        assert!(labels == [
+            "work: 25 minutes",
+            "break: 5 minutes",
+            "work: 25 minutes",
+            "break: 5 minutes",
+            "work: 10 minutes",
+        ]);
+        assert!(segments[4].start == Duration::new(60 * 60));
+        assert!(segments[4].kind == SegmentKind::Work);
+    }
+
+    #[test]
+    fn test_split_into_edge_cases() {
+        let exact = Duration::new(50 * 60).split_into(Duration::new(25 * 60), Duration::new(300));
+        assert!(exact.count() == 3);
+        let back_to_back = Duration::new(3000).split_into(Duration::new(1500), Duration::ZERO);
+        let kinds = back_to_back.map(|s| s.kind).collect::<Vec<SegmentKind>>();
+        assert!(kinds == [SegmentKind::Work, SegmentKind::Work]);
+        assert!(Duration::new(60).split_into(Duration::ZERO, Duration::new(5)).next().is_none());
+        assert!(Duration::ZERO.split_into(Duration::new(60), Duration::new(5)).next().is_none());
+    }
+}
@@ -0,0 +1,58 @@
+use criterion::measurement::ValueFormatter;
+use criterion::Throughput;
+
+use si::{prefix_scale, time_scale, SiFormatter};
+
+impl ValueFormatter for SiFormatter {
+    fn scale_values(&self, typical_value: f64, values: &mut [f64]) -> &'static str {
+        let (symbol, scale) = time_scale(typical_value);
+        for value in values.iter_mut() {
+            *value /= scale;
+        }
+        symbol
+    }
+
+    fn scale_throughputs(
+        &self,
+        typical_value: f64,
+        throughput: &Throughput,
+        values: &mut [f64],
+    ) -> &'static str {
+        let (amount, base, units): (u64, f64, [&'static str; 4]) = match *throughput {
+            Throughput::Bytes(n) => (n, 1024.0, ["B/s", "KiB/s", "MiB/s", "GiB/s"]),
+            Throughput::BytesDecimal(n) => (n, 1000.0, ["B/s", "KB/s", "MB/s", "GB/s"]),
+            Throughput::Elements(n) => (n, 1000.0, ["elem/s", "Kelem/s", "Melem/s", "Gelem/s"]),
+        };
+        let per_sec = |nanos: f64| amount as f64 * 1e9 / nanos;
+        let (_, factor) = prefix_scale(per_sec(typical_value), base);
+        for value in values.iter_mut() {
+            *value = per_sec(*value) / factor;
+        }
+        units[(factor.ln() / base.ln()).round() as usize]
+    }
+
+    fn scale_for_machines(&self, _values: &mut [f64]) -> &'static str {
+        "ns"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use criterion::measurement::ValueFormatter;
+    use criterion::Throughput;
+
+    use si::SiFormatter;
+
+    #[test]
+    fn test_criterion_value_formatter() {
+        let mut values = [1_234_567.0, 2_000_000.0];
+        assert!(SiFormatter.scale_values(1_234_567.0, &mut values) == "ms");
+        assert!(values == [1.234567, 2.0]);
+
+        let mut values = [1e9];
+        let unit = SiFormatter.scale_throughputs(1e9, &Throughput::Bytes(2 * 1024 * 1024), &mut values);
+        assert!(unit == "MiB/s" && values == [2.0]);
+        let unit = SiFormatter.scale_throughputs(1.0, &Throughput::Elements(5), &mut [1.0]);
+        assert!(unit == "Gelem/s");
+    }
+}
@@ -0,0 +1,136 @@
+//! Auto-scaling SI formatting for timings, in the manner of benchmark harnesses such as
+//! criterion, ex) "1.23 ms", "456 µs" or "789 ns".
+//!
+//! As a [`Duration`](../duration/struct.Duration.html) counts whole seconds, sub-second timings
+//! are taken as `std::time::Duration`s or as nanoseconds.
+
+use std::time::Duration as StdDuration;
+
+use duration::Duration;
+
+#[cfg(feature = "criterion")]
+mod impl_criterion;
+
+const SI_UNITS: [(&str, f64); 4] = [("s", 1e9), ("ms", 1e6), ("µs", 1e3), ("ns", 1.0)];
+
+/// The SI time unit which `nanos` is best shown in, along with its length in nanoseconds.
+fn time_scale(nanos: f64) -> (&'static str, f64) {
+    *SI_UNITS
+        .iter()
+        .find(|&&(_, scale)| nanos >= scale)
+        .unwrap_or(&SI_UNITS[3])
+}
+
+/// The prefix which `amount` is best shown with in steps of `base`, ex) 1000 or 1024, along with
+/// its factor. Prefixes are "", "K", "M" and "G", or "", "Ki", "Mi" and "Gi" in steps of 1024.
+fn prefix_scale(amount: f64, base: f64) -> (&'static str, f64) {
+    let prefixes: [&str; 4] = if base == 1024.0 {
+        ["", "Ki", "Mi", "Gi"]
+    } else {
+        ["", "K", "M", "G"]
+    };
+    let mut found = (prefixes[0], 1.0);
+    let mut factor = 1.0;
+    for prefix in prefixes.iter() {
+        if amount >= factor {
+            found = (prefix, factor);
+        }
+        factor *= base;
+    }
+    found
+}
+
+/// Formats `amount` with three significant digits, ex) "1.23", "45.6" or "456".
+fn significant(amount: f64) -> String {
+    let decimals = if amount >= 100.0 {
+        0
+    } else if amount >= 10.0 {
+        1
+    } else {
+        2
+    };
+    format!("{:.*}", decimals, amount)
+}
+
+/// Formats the time and throughput columns of benchmark reports. Implemented by
+/// [`SiFormatter`](struct.SiFormatter.html), which with the `criterion` feature also implements
+/// criterion's `ValueFormatter`.
+pub trait MeasurementFormatter {
+    /// Formats a timing of `nanos` nanoseconds, ex) "1.23 ms".
+    fn format_time(&self, nanos: f64) -> String;
+
+    /// Formats `per_sec` items of `unit` per second, ex) "1.23 Melem/s" for "elem".
+    fn format_throughput(&self, per_sec: f64, unit: &str) -> String;
+}
+
+/// Formats measurements with the auto-scaling SI units of
+/// [`format_si_nanos`](fn.format_si_nanos.html).
+#[derive(PartialEq, Eq, Clone, Copy, Debug, Default)]
+pub struct SiFormatter;
+
+impl MeasurementFormatter for SiFormatter {
+    fn format_time(&self, nanos: f64) -> String {
+        format_si_nanos(nanos)
+    }
+
+    fn format_throughput(&self, per_sec: f64, unit: &str) -> String {
+        let (prefix, factor) = prefix_scale(per_sec, 1000.0);
+        format!("{} {}{}/s", significant(per_sec / factor), prefix, unit)
+    }
+}
+
+/// Formats `nanos` nanoseconds in the largest of s, ms, µs and ns which keeps the amount at or
+/// above one, with three significant digits, ex) 1,234,567 ns -> "1.23 ms". Nanoseconds are
+/// whole, ex) "789 ns", and seconds do not scale further, ex) "7199 s".
+pub fn format_si_nanos(nanos: f64) -> String {
+    let (symbol, scale) = time_scale(nanos);
+    if scale == 1.0 {
+        return format!("{:.0} {}", nanos, symbol);
+    }
+    format!("{} {}", significant(nanos / scale), symbol)
+}
+
+/// Formats `d` like [`format_si_nanos`](fn.format_si_nanos.html), ex) "1.23 ms".
+pub fn format_si(d: StdDuration) -> String {
+    format_si_nanos(d.as_nanos() as f64)
+}
+
+impl Duration {
+    /// Formats `self` in seconds like [`format_si`](../si/fn.format_si.html), ex) "2.00 s" or
+    /// "7199 s".
+    pub fn format_si(&self) -> String {
+        format_si_nanos(self.total_seconds() as f64 * 1e9)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration as StdDuration;
+
+    use duration::Duration;
+    use si::{format_si, format_si_nanos, MeasurementFormatter, SiFormatter};
+
+    #[test]
+    fn test_format_si() {
+        assert!(format_si(StdDuration::from_nanos(1_234_567)) == "1.23 ms");
+        assert!(format_si(StdDuration::from_nanos(456_000)) == "456 µs");
+        assert!(format_si(StdDuration::from_nanos(45_600)) == "45.6 µs");
+        assert!(format_si(StdDuration::from_nanos(789)) == "789 ns");
+        assert!(format_si(StdDuration::from_nanos(0)) == "0 ns");
+        assert!(format_si(StdDuration::from_millis(1500)) == "1.50 s");
+        assert!(format_si_nanos(0.5) == "0 ns");
+    }
+
+    #[test]
+    fn test_duration_format_si() {
+        assert!(Duration::new(2).format_si() == "2.00 s");
+        assert!(Duration::new(7199).format_si() == "7199 s");
+    }
+
+    #[test]
+    fn test_si_formatter() {
+        assert!(SiFormatter.format_time(1_234_567.0) == "1.23 ms");
+        assert!(SiFormatter.format_throughput(1_234_567.0, "elem") == "1.23 Melem/s");
+        assert!(SiFormatter.format_throughput(12.0, "B") == "12.0 B/s");
+    }
+}
@@ -0,0 +1,259 @@
+//! Durations which may point into the past, ex) the time left until a deadline that has already
+//! passed.
+
+use std::convert::TryFrom;
+use std::fmt;
+use std::ops::{Add, Neg, Sub};
+use std::time::SystemTime;
+
+use calendar::{unix_secs, UnixTimestamp};
+use duration::Duration;
+
+/// A [`Duration`](../duration/struct.Duration.html) along with a direction, negative when it
+/// points into the past.
+#[derive(PartialEq, Clone, Copy)]
+pub struct SignedDuration {
+    pub negative: bool,
+    pub magnitude: Duration,
+}
+
+impl SignedDuration {
+    /// From signed seconds, ex) -90 is 1 minute and 30 seconds ago.
+    pub fn from_secs(secs: i64) -> Self {
+        SignedDuration {
+            negative: secs < 0,
+            magnitude: Duration::new(secs.unsigned_abs() as usize),
+        }
+    }
+
+    pub fn is_negative(&self) -> bool {
+        self.negative && !self.magnitude.is_zero()
+    }
+
+    /// The magnitude regardless of direction.
+    pub fn abs(&self) -> Duration {
+        self.magnitude
+    }
+
+    /// -1 when negative, 0 when zero and 1 when positive.
+    pub fn signum(&self) -> i8 {
+        if self.magnitude.is_zero() {
+            0
+        } else if self.negative {
+            -1
+        } else {
+            1
+        }
+    }
+
+    /// Whether a deadline measured with
+    /// [`Duration::until`](../duration/struct.Duration.html#method.until) has passed.
+    pub fn is_overdue(&self) -> bool {
+        self.is_negative()
+    }
+
+    /// How long ago the deadline passed, if it has, ex) "passed by 3 minutes".
+    pub fn overdue_by(&self) -> Option<Duration> {
+        if self.is_overdue() {
+            Some(self.magnitude)
+        } else {
+            None
+        }
+    }
+
+    /// How long is left until the deadline, if it has not passed.
+    pub fn remaining(&self) -> Option<Duration> {
+        if self.is_overdue() {
+            None
+        } else {
+            Some(self.magnitude)
+        }
+    }
+
+    fn secs(&self) -> i128 {
+        let secs = self.magnitude.total_seconds() as i128;
+        if self.negative {
+            -secs
+        } else {
+            secs
+        }
+    }
+
+    /// Panics like `Duration + Duration` when the magnitude does not fit in a `usize`.
+    fn from_i128(secs: i128) -> Self {
+        let magnitude = usize::try_from(secs.unsigned_abs()).expect("signed duration overflow");
+        SignedDuration {
+            negative: secs < 0,
+            magnitude: Duration::new(magnitude),
+        }
+    }
+}
+
+impl From<Duration> for SignedDuration {
+    fn from(magnitude: Duration) -> Self {
+        SignedDuration {
+            negative: false,
+            magnitude,
+        }
+    }
+}
+
+impl Neg for SignedDuration {
+    type Output = SignedDuration;
+
+    /// Zero stays non-negative.
+    fn neg(self) -> SignedDuration {
+        SignedDuration {
+            negative: !self.negative && !self.magnitude.is_zero(),
+            magnitude: self.magnitude,
+        }
+    }
+}
+
+impl Add for SignedDuration {
+    type Output = SignedDuration;
+
+    fn add(self, rhs: SignedDuration) -> SignedDuration {
+        SignedDuration::from_i128(self.secs() + rhs.secs())
+    }
+}
+
+impl Sub for SignedDuration {
+    type Output = SignedDuration;
+
+    fn sub(self, rhs: SignedDuration) -> SignedDuration {
+        SignedDuration::from_i128(self.secs() - rhs.secs())
+    }
+}
+
+impl Add<Duration> for SignedDuration {
+    type Output = SignedDuration;
+
+    fn add(self, rhs: Duration) -> SignedDuration {
+        self + SignedDuration::from(rhs)
+    }
+}
+
+impl Sub<Duration> for SignedDuration {
+    type Output = SignedDuration;
+
+    fn sub(self, rhs: Duration) -> SignedDuration {
+        self - SignedDuration::from(rhs)
+    }
+}
+
+impl Sub for Duration {
+    type Output = SignedDuration;
+
+    /// The signed difference, negative when `rhs` is the longer one, ex) 1 minute - 90 seconds
+    /// is 30 seconds ago.
+    fn sub(self, rhs: Duration) -> SignedDuration {
+        SignedDuration::from(self) - rhs
+    }
+}
+
+impl fmt::Display for SignedDuration {
+    /// Words the direction around the prose of the magnitude, ex) "in 2 minutes", "2 minutes
+    /// ago" or "now". The alternate form `{:#}` prefixes a minus sign instead, ex) "-2 minutes.".
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if f.alternate() {
+            if self.is_negative() {
+                write!(f, "-")?;
+            }
+            return write!(f, "{}", self.magnitude);
+        }
+        if self.magnitude.is_zero() {
+            return f.write_str("now");
+        }
+        let prose = self.magnitude.to_string();
+        let prose = prose.trim_end_matches('.');
+        if self.negative {
+            write!(f, "{} ago", prose)
+        } else {
+            write!(f, "in {}", prose)
+        }
+    }
+}
+
+impl Duration {
+    /// The time from now until `timestamp`, negative once it has passed, ex) a countdown to a
+    /// Unix epoch seconds value or, behind the `chrono` and `time` features, a zoned date time.
+    pub fn until<T: UnixTimestamp>(timestamp: T) -> SignedDuration {
+        SignedDuration::from_secs(timestamp.unix_timestamp() - unix_secs(SystemTime::now()))
+    }
+
+    /// The time elapsed since `timestamp`, negative while it is still ahead.
+    pub fn since_timestamp<T: UnixTimestamp>(timestamp: T) -> SignedDuration {
+        SignedDuration::from_secs(unix_secs(SystemTime::now()) - timestamp.unix_timestamp())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::{Duration as StdDuration, SystemTime};
+
+    use calendar::unix_secs;
+    use duration::Duration;
+    use signed::SignedDuration;
+
+    #[test]
+    fn test_from_secs() {
+        let ago = SignedDuration::from_secs(-90);
+        assert!(ago.is_negative());
+        assert!(ago.magnitude == Duration::new(90));
+        assert!(ago.to_string() == "1 minute and 30 seconds ago");
+        assert!(format!("{:#}", ago) == "-1 minute and 30 seconds.");
+        assert!(SignedDuration::from_secs(7200).to_string() == "in 2 hours");
+        assert!(SignedDuration::from_secs(0).to_string() == "now");
+        assert!(!SignedDuration::from_secs(0).is_negative());
+        assert!(SignedDuration::from_secs(i64::MIN).magnitude == Duration::new(1 << 63));
+    }
+
+    #[test]
+    fn test_accessors() {
+        let late = SignedDuration::from_secs(-180);
+        assert!(late.abs() == Duration::new(180));
+        assert!(late.signum() == -1);
+        assert!(late.is_overdue());
+        assert!(late.overdue_by() == Some(Duration::new(180)));
+        assert!(late.remaining().is_none());
+
+        let ahead = SignedDuration::from_secs(60);
+        assert!(ahead.signum() == 1);
+        assert!(!ahead.is_overdue());
+        assert!(ahead.overdue_by().is_none());
+        assert!(ahead.remaining() == Some(Duration::new(60)));
+
+        let now = SignedDuration::from_secs(0);
+        assert!(now.signum() == 0);
+        assert!(!now.is_overdue());
+        assert!(now.remaining() == Some(Duration::ZERO));
+    }
+
+    #[test]
+    fn test_arithmetic() {
+        let d = SignedDuration::from(Duration::new(60));
+        assert!(-d == SignedDuration::from_secs(-60));
+        assert!(-(-d) == d);
+        assert!(-SignedDuration::from_secs(0) == SignedDuration::from_secs(0));
+        assert!(d - Duration::new(90) == SignedDuration::from_secs(-30));
+        assert!(SignedDuration::from_secs(-30) + Duration::new(90) == d);
+        assert!(d + SignedDuration::from_secs(-60) == SignedDuration::from_secs(0));
+        assert!(d - SignedDuration::from_secs(-60) == SignedDuration::from_secs(120));
+        assert!(Duration::new(60) - Duration::new(90) == SignedDuration::from_secs(-30));
+        assert!((Duration::new(3600) - Duration::new(600)).to_string() == "in 50 minutes");
+    }
+
+    #[test]
+    fn test_until_and_since() {
+        let now = unix_secs(SystemTime::now());
+        let ahead = Duration::until(now + 3600);
+        assert!(!ahead.is_negative());
+        assert!(ahead.magnitude.total_seconds() <= 3600 && ahead.magnitude.total_seconds() >= 3590);
+
+        let passed = Duration::until(SystemTime::now() - StdDuration::from_secs(120));
+        assert!(passed.is_negative());
+        assert!(Duration::since_timestamp(now - 60).magnitude.total_seconds() >= 60);
+        assert!(Duration::since_timestamp(now + 60).is_negative());
+    }
+}
@@ -0,0 +1,252 @@
+use std::fmt;
+use std::iter::FromIterator;
+
+use duration::Duration;
+use error::Error;
+
+/// Finds the duration of `durations` closest to `target`, preferring the earliest on ties.
+/// Returns `None` if `durations` is empty.
+pub fn nearest(durations: &[Duration], target: Duration) -> Option<&Duration> {
+    let target = target.total_seconds();
+    durations.iter().min_by_key(|d| {
+        let secs = d.total_seconds();
+        secs.max(target) - secs.min(target)
+    })
+}
+
+/// Counts `durations` into the bins delimited by ascending `edges`. Returns `edges.len() + 1`
+/// counts: durations below the first edge, durations in `[edges[i - 1], edges[i])` for each `i`,
+/// then durations at or above the last edge.
+pub fn bin(durations: &[Duration], edges: &[Duration]) -> Vec<usize> {
+    let edges = edges.iter().map(Duration::total_seconds).collect::<Vec<usize>>();
+    let mut counts = vec![0; edges.len() + 1];
+    for d in durations {
+        let secs = d.total_seconds();
+        counts[edges.iter().take_while(|&&edge| edge <= secs).count()] += 1;
+    }
+    counts
+}
+
+/// Accumulates durations, ex) request latencies, and summarizes them as durations.
+#[derive(PartialEq, Clone, Default)]
+pub struct DurationStats {
+    secs: Vec<usize>,
+    sorted: bool,
+}
+
+impl DurationStats {
+    pub fn new() -> Self {
+        DurationStats::default()
+    }
+
+    pub fn push(&mut self, d: Duration) {
+        self.secs.push(d.total_seconds());
+        self.sorted = false;
+    }
+
+    pub fn len(&self) -> usize {
+        self.secs.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.secs.is_empty()
+    }
+
+    pub fn min(&self) -> Option<Duration> {
+        self.secs.iter().min().map(|&secs| Duration::new(secs))
+    }
+
+    pub fn max(&self) -> Option<Duration> {
+        self.secs.iter().max().map(|&secs| Duration::new(secs))
+    }
+
+    /// The arithmetic mean, rounded to the nearest second.
+    pub fn mean(&self) -> Option<Duration> {
+        if self.secs.is_empty() {
+            return None;
+        }
+        let sum = self.secs.iter().map(|&secs| secs as u128).sum::<u128>();
+        let n = self.secs.len() as u128;
+        Some(Duration::new(((sum + n / 2) / n) as usize))
+    }
+
+    pub fn median(&mut self) -> Option<Duration> {
+        self.percentile(50.0)
+    }
+
+    /// The smallest duration which at least `p` percent of the durations are at or below, ex)
+    /// `percentile(95.0)` for the p95. `p` is clamped to `0.0..=100.0`.
+    pub fn percentile(&mut self, p: f64) -> Option<Duration> {
+        if self.secs.is_empty() {
+            return None;
+        }
+        if !self.sorted {
+            self.secs.sort_unstable();
+            self.sorted = true;
+        }
+        let p = if p.is_nan() { 0.0 } else { p.clamp(0.0, 100.0) };
+        let rank = (p / 100.0 * self.secs.len() as f64).ceil() as usize;
+        Some(Duration::new(self.secs[rank.max(1) - 1]))
+    }
+}
+
+impl Extend<Duration> for DurationStats {
+    fn extend<I: IntoIterator<Item = Duration>>(&mut self, iter: I) {
+        for d in iter {
+            self.push(d);
+        }
+    }
+}
+
+impl FromIterator<Duration> for DurationStats {
+    fn from_iter<I: IntoIterator<Item = Duration>>(iter: I) -> Self {
+        let mut stats = DurationStats::new();
+        stats.extend(iter);
+        stats
+    }
+}
+
+impl fmt::Display for DurationStats {
+    /// Summarizes in compact form, ex) "3 samples: min 1s, mean 2s, median 2s, p95 3s, max 3s".
+    /// Without any duration, formats as "no samples".
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mut sorted = self.clone();
+        match (sorted.min(), sorted.mean(), sorted.median(), sorted.percentile(95.0), sorted.max()) {
+            (Some(min), Some(mean), Some(median), Some(p95), Some(max)) => write!(
+                f,
+                "{} sample{}: min {}, mean {}, median {}, p95 {}, max {}",
+                self.len(),
+                if self.len() != 1 { "s" } else { "" },
+                min.format_compact(),
+                mean.format_compact(),
+                median.format_compact(),
+                p95.format_compact(),
+                max.format_compact(),
+            ),
+            _ => f.write_str("no samples"),
+        }
+    }
+}
+
+/// An exponential moving average of durations, ex) to smooth the ETA of a progress display.
+#[derive(PartialEq, Clone, Copy, Debug)]
+pub struct Ewma {
+    alpha: f64,
+    secs: Option<f64>,
+}
+
+impl Ewma {
+    /// Weighs each new duration by `alpha` and the previous estimate by `1 - alpha`, so a larger
+    /// `alpha` follows changes faster. An `alpha` outside `(0.0, 1.0]`, or NaN, is an
+    /// [`Error::InvalidFormat`](../error/enum.Error.html).
+    pub fn new(alpha: f64) -> Result<Self, Error> {
+        if !(alpha > 0.0 && alpha <= 1.0) {
+            return Err(Error::InvalidFormat);
+        }
+        Ok(Ewma { alpha, secs: None })
+    }
+
+    /// Folds `d` into the average and returns the new estimate. The first duration becomes the
+    /// estimate as is.
+    pub fn update(&mut self, d: Duration) -> Duration {
+        let secs = d.total_seconds() as f64;
+        let next = match self.secs {
+            Some(prev) => prev + self.alpha * (secs - prev),
+            None => secs,
+        };
+        self.secs = Some(next);
+        Ewma::to_duration(next)
+    }
+
+    /// The current estimate, rounded to the nearest second and saturating at
+    /// [`Duration::MAX`](../duration/struct.Duration.html#associatedconstant.MAX). `None` until
+    /// the first update.
+    pub fn estimate(&self) -> Option<Duration> {
+        self.secs.map(Ewma::to_duration)
+    }
+
+    /// Forgets every duration seen so far.
+    pub fn reset(&mut self) {
+        self.secs = None;
+    }
+
+    fn to_duration(secs: f64) -> Duration {
+        // Float to integer casts saturate.
+        Duration::new(secs.round() as usize)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use duration::Duration;
+    use error::Error;
+    use stats::{bin, nearest, DurationStats, Ewma};
+
+    #[test]
+    fn test_nearest() {
+        let durations = [Duration::new(60), Duration::new(300), Duration::new(900)];
+        assert!(nearest(&durations, Duration::new(200)) == Some(&durations[1]));
+        assert!(nearest(&durations, Duration::new(180)) == Some(&durations[0]));
+        assert!(nearest(&durations, Duration::new(5000)) == Some(&durations[2]));
+        assert!(nearest(&[], Duration::new(5)).is_none());
+    }
+
+    #[test]
+    fn test_bin() {
+        let durations = [1, 59, 60, 61, 3599, 3600, 86_400]
+            .iter()
+            .map(|&secs| Duration::new(secs))
+            .collect::<Vec<Duration>>();
+        let edges = [Duration::new(60), Duration::new(3600)];
+        assert!(bin(&durations, &edges) == vec![2, 3, 2]);
+        assert!(bin(&durations, &[]) == vec![7]);
+    }
+
+    #[test]
+    fn test_duration_stats() {
+        let mut stats = (1..=20).map(Duration::new).collect::<DurationStats>();
+        assert!(stats.len() == 20);
+        assert!(stats.min() == Some(Duration::new(1)));
+        assert!(stats.max() == Some(Duration::new(20)));
+        assert!(stats.mean() == Some(Duration::new(11)));
+        assert!(stats.median() == Some(Duration::new(10)));
+        assert!(stats.percentile(95.0) == Some(Duration::new(19)));
+        assert!(stats.percentile(0.0) == Some(Duration::new(1)));
+        assert!(stats.percentile(250.0) == Some(Duration::new(20)));
+
+        stats.push(Duration::new(3600));
+        assert!(stats.max() == Some(Duration::new(3600)));
+        assert!(stats.to_string() == "21 samples: min 1s, mean 3m 1s, median 11s, p95 20s, max 1h");
+    }
+
+    #[test]
+    fn test_duration_stats_empty() {
+        let mut stats = DurationStats::new();
+        assert!(stats.is_empty());
+        assert!(stats.mean().is_none() && stats.median().is_none());
+        assert!(stats.to_string() == "no samples");
+    }
+
+    #[test]
+    fn test_ewma() {
+        let mut ewma = Ewma::new(0.5).unwrap();
+        assert!(ewma.estimate().is_none());
+        assert!(ewma.update(Duration::new(100)) == Duration::new(100));
+        assert!(ewma.update(Duration::new(200)) == Duration::new(150));
+        assert!(ewma.update(Duration::new(0)) == Duration::new(75));
+        ewma.reset();
+        assert!(ewma.estimate().is_none());
+
+        let mut ewma = Ewma::new(1.0).unwrap();
+        ewma.update(Duration::MAX);
+        assert!(ewma.update(Duration::MAX) == Duration::MAX);
+    }
+
+    #[test]
+    fn test_ewma_alpha() {
+        for &alpha in [0.0, -0.5, 1.5, f64::NAN, f64::INFINITY].iter() {
+            assert!(Ewma::new(alpha) == Err(Error::InvalidFormat));
+        }
+        assert!(Ewma::new(f64::MIN_POSITIVE).is_ok());
+    }
+}
@@ -0,0 +1,93 @@
+//! Duration cells for CLI tables, right-aligned in compact form so that the units of a column
+//! line up, ex) "   45s" above "1h 30m". With the `comfy-table` feature, cells also build
+//! right-aligned `comfy_table::Cell`s.
+
+use std::fmt;
+
+use duration::{Duration, Style};
+
+/// Displays a [`Duration`](../duration/struct.Duration.html) in a table cell. A width given to
+/// the formatter right-aligns it, ex) `format!("{:8}", d.cell())`, which `tabled` and plain
+/// `format!` tables both rely on.
+#[derive(PartialEq, Clone, Copy)]
+pub struct CellDisplay {
+    pub duration: Duration,
+    pub style: Style,
+}
+
+impl CellDisplay {
+    pub fn style(mut self, style: Style) -> Self {
+        self.style = style;
+        self
+    }
+}
+
+impl fmt::Display for CellDisplay {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let s = self.duration.format_style(self.style);
+        match f.width() {
+            Some(width) => write!(f, "{:>1$}", s, width),
+            None => f.write_str(&s),
+        }
+    }
+}
+
+impl Duration {
+    /// Wraps `self` for display in a table cell, in compact form unless restyled.
+    pub fn cell(&self) -> CellDisplay {
+        CellDisplay {
+            duration: *self,
+            style: Style::Compact,
+        }
+    }
+}
+
+/// Formats `durations` as a column of cells, right-aligned to the widest of them.
+pub fn column(durations: &[Duration]) -> Vec<String> {
+    let cells = durations.iter().map(|d| d.format_compact()).collect::<Vec<String>>();
+    let width = cells.iter().map(|cell| cell.chars().count()).max().unwrap_or(0);
+    cells.iter().map(|cell| format!("{:>1$}", cell, width)).collect()
+}
+
+#[cfg(feature = "comfy-table")]
+impl CellDisplay {
+    /// A right-aligned `comfy_table::Cell`. Converting with `Cell::from` instead keeps the
+    /// column's alignment.
+    pub fn comfy_cell(&self) -> ::comfy_table::Cell {
+        ::comfy_table::Cell::new(self).set_alignment(::comfy_table::CellAlignment::Right)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use duration::{Duration, Style};
+    use table::column;
+
+    #[test]
+    fn test_cell_display() {
+        let d = Duration::new(5400);
+        assert!(d.cell().to_string() == "1h 30m");
+        assert!(format!("{:8}", d.cell()) == "  1h 30m");
+        assert!(format!("{:3}", d.cell()) == "1h 30m");
+        assert!(format!("{}", d.cell().style(Style::Approximate)) == "about 2 hours");
+    }
+
+    #[test]
+    fn test_column() {
+        let cells = column(&[Duration::new(45), Duration::new(5400), Duration::new(0)]);
+        assert!(cells == ["   45s", "1h 30m", "    0s"]);
+        assert!(column(&[]).is_empty());
+    }
+
+    #[cfg(feature = "comfy-table")]
+    #[test]
+    fn test_comfy_table_cell() {
+        let mut table = ::comfy_table::Table::new();
+        table.load_preset(::comfy_table::presets::ASCII_MARKDOWN);
+        table.add_row(vec![Duration::new(45).cell().comfy_cell()]);
+        table.add_row(vec![Duration::new(5400).cell().comfy_cell()]);
+        let rendered = table.to_string();
+        assert!(rendered.contains("|    45s |"));
+        assert!(rendered.contains("| 1h 30m |"));
+    }
+}
@@ -0,0 +1,216 @@
+//! Successive multiples of a step with labels, ex) for the axis of a chart or for periodic status
+//! lines.
+
+use std::ops::Range;
+
+use duration::{Duration, Style};
+
+const MINUTE: usize = 60;
+const HOUR: usize = 60 * MINUTE;
+const DAY: usize = 24 * HOUR;
+const YEAR: usize = 365 * DAY;
+
+/// Tick steps which read well on an axis, ex) 15 seconds or 6 hours, ordered by length. Beyond
+/// these, steps continue as 1, 2 and 5 times powers of ten years.
+const NICE_STEPS: [usize; 27] = [
+    1, 2, 5, 10, 15, 30,
+    MINUTE, 2 * MINUTE, 5 * MINUTE, 10 * MINUTE, 15 * MINUTE, 30 * MINUTE,
+    HOUR, 2 * HOUR, 3 * HOUR, 6 * HOUR, 12 * HOUR,
+    DAY, 2 * DAY, 7 * DAY, 14 * DAY, 30 * DAY, 90 * DAY, 180 * DAY,
+    YEAR, 2 * YEAR, 5 * YEAR,
+];
+
+/// One multiple of the step of [`Ticks`](struct.Ticks.html).
+#[derive(PartialEq, Clone)]
+pub struct Tick {
+    pub at: Duration,
+    pub label: String,
+}
+
+/// Iterates over the cumulative durations `step`, `2 * step`, ... with labels, ex) "5 minutes",
+/// "10 minutes", ... It ends once the next tick would overflow, or right away for a zero step.
+#[derive(Clone)]
+pub struct Ticks {
+    step: usize,
+    next: Option<usize>,
+    /// Exclusive, see [`iter_range`](../duration/struct.Duration.html#method.iter_range).
+    end: Option<usize>,
+    style: Style,
+}
+
+impl Ticks {
+    /// Labels ticks as prose without the trailing period, ex) "1 hour and 30 minutes".
+    pub fn new(step: Duration) -> Self {
+        let step = step.total_seconds();
+        Ticks {
+            step,
+            next: if step == 0 { None } else { Some(step) },
+            end: None,
+            style: Style::Prose,
+        }
+    }
+
+    /// Labels ticks in `style` instead, ex) "5m" with `Style::Compact`.
+    pub fn style(mut self, style: Style) -> Self {
+        self.style = style;
+        self
+    }
+}
+
+/// Formats `d` as a short label in `style`, dropping the trailing period of prose.
+fn label(d: &Duration, style: Style) -> String {
+    let s = d.format_style(style);
+    match style {
+        Style::Prose => s.trim_end_matches('.').to_string(),
+        _ => s,
+    }
+}
+
+/// The step chosen by [`nice_ticks`](../duration/struct.Duration.html#method.nice_ticks) along
+/// with the ticks it places.
+#[derive(PartialEq, Clone)]
+pub struct NiceTicks {
+    pub step: Duration,
+    pub ticks: Vec<Tick>,
+}
+
+/// The steps of `NICE_STEPS` followed by 10, 20, 50, 100, ... years, until overflow.
+fn nice_steps() -> impl Iterator<Item = usize> {
+    let decades = (1..).map_while(|exp| 10usize.checked_pow(exp)?.checked_mul(YEAR));
+    NICE_STEPS.iter().cloned().chain(decades.flat_map(|base| {
+        [1, 2, 5].iter().filter_map(move |factor| base.checked_mul(*factor))
+    }))
+}
+
+impl Duration {
+    /// Iterates from `start` up to, but excluding, `end` in strides of `step`, with labels like
+    /// [`Ticks`](../ticks/struct.Ticks.html), ex) 9:00, 9:15, 9:30 and 9:45 for schedule slots
+    /// of 15 minutes until 10:00. A zero `step` yields nothing.
+    pub fn iter_range(start: Duration, end: Duration, step: Duration) -> Ticks {
+        let (start, step) = (start.total_seconds(), step.total_seconds());
+        Ticks {
+            step,
+            next: if step == 0 { None } else { Some(start) },
+            end: Some(end.total_seconds()),
+            style: Style::Prose,
+        }
+    }
+
+    /// Chooses the shortest human-friendly step, ex) 1s, 5s, 15s, 1m, 5m, 15m or 1h, which splits
+    /// `range` into at most `approx_count` intervals, and places ticks at the multiples of that
+    /// step within `range`, both ends included. Ticks are labelled in compact form, ex) "1h 30m".
+    /// An `approx_count` of zero is treated as one.
+    pub fn nice_ticks(range: Range<Duration>, approx_count: usize) -> NiceTicks {
+        let (start, end) = (range.start.total_seconds(), range.end.total_seconds());
+        let span = end.saturating_sub(start);
+        let count = approx_count.max(1);
+        let step = nice_steps()
+            .find(|step| span.div_ceil(*step) <= count)
+            .unwrap_or(usize::MAX);
+
+        let first = start.div_ceil(step).checked_mul(step);
+        let ticks = first
+            .into_iter()
+            .flat_map(|first| (first..=end).step_by(step))
+            .map(|secs| {
+                let at = Duration::new(secs);
+                Tick {
+                    label: label(&at, Style::Compact),
+                    at,
+                }
+            })
+            .collect();
+        NiceTicks {
+            step: Duration::new(step),
+            ticks,
+        }
+    }
+}
+
+impl Iterator for Ticks {
+    type Item = Tick;
+
+    fn next(&mut self) -> Option<Tick> {
+        let secs = self.next.filter(|secs| self.end.is_none_or(|end| *secs < end))?;
+        let at = Duration::new(secs);
+        self.next = secs.checked_add(self.step);
+        Some(Tick {
+            label: label(&at, self.style),
+            at,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use duration::{Duration, Style};
+    use ticks::Ticks;
+
+    fn nice_labels(range: std::ops::Range<Duration>, count: usize) -> (usize, Vec<String>) {
+        let nice = Duration::nice_ticks(range, count);
+        (nice.step.total_seconds(), nice.ticks.into_iter().map(|tick| tick.label).collect())
+    }
+
+    #[test]
+    fn test_ticks() {
+        let labels = Ticks::new(Duration::new(300))
+            .take(3)
+            .map(|tick| tick.label)
+            .collect::<Vec<String>>();
+        assert!(labels == ["5 minutes", "10 minutes", "15 minutes"]);
+
+        let mut ticks = Ticks::new(Duration::new(5400)).style(Style::Compact);
+        assert!(ticks.next().unwrap().label == "1h 30m");
+        let tick = ticks.next().unwrap();
+        assert!(tick.at == Duration::new(10_800) && tick.label == "3h");
+    }
+
+    #[test]
+    fn test_iter_range() {
+        let (start, end) = (Duration::new(9 * 3600), Duration::new(10 * 3600));
+        let slots = Duration::iter_range(start, end, Duration::new(900))
+            .style(Style::Compact)
+            .map(|tick| tick.label)
+            .collect::<Vec<String>>();
+        assert!(slots == ["9h", "9h 15m", "9h 30m", "9h 45m"]);
+
+        let minute = Duration::new(60);
+        let reminders = Duration::iter_range(minute, Duration::new(181), minute);
+        let labels = reminders.map(|tick| tick.label).collect::<Vec<String>>();
+        assert!(labels == ["1 minute", "2 minutes", "3 minutes"]);
+        assert!(Duration::iter_range(Duration::ZERO, minute, Duration::ZERO).next().is_none());
+        assert!(Duration::iter_range(minute, minute, Duration::new(1)).next().is_none());
+    }
+
+    #[test]
+    fn test_ticks_end() {
+        assert!(Ticks::new(Duration::new(0)).next().is_none());
+        assert!(Ticks::new(Duration::new(usize::MAX / 2 + 1)).count() == 1);
+    }
+
+    #[test]
+    fn test_nice_ticks() {
+        let (step, labels) = nice_labels(Duration::new(0)..Duration::new(3600), 4);
+        assert!(step == 900);
+        assert!(labels == ["0s", "15m", "30m", "45m", "1h"]);
+
+        let (step, labels) = nice_labels(Duration::new(70)..Duration::new(290), 5);
+        assert!(step == 60);
+        assert!(labels == ["2m", "3m", "4m"]);
+
+        let (step, labels) = nice_labels(Duration::new(0)..Duration::new(7), 10);
+        assert!(step == 1 && labels.len() == 8);
+    }
+
+    #[test]
+    fn test_nice_ticks_edge_cases() {
+        let (step, labels) = nice_labels(Duration::new(5)..Duration::new(5), 0);
+        assert!(step == 1 && labels == ["5s"]);
+
+        let (step, _) = nice_labels(Duration::new(0)..Duration::new(40 * 365 * 86_400), 4);
+        assert!(step == 10 * 365 * 86_400);
+
+        let (_, labels) = nice_labels(Duration::new(100)..Duration::new(10), 3);
+        assert!(labels.is_empty());
+    }
+}
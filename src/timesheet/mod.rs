@@ -0,0 +1,312 @@
+//! Helpers for time tracking, ex) reading the hours worked from "9am to 5:30pm" and summing
+//! them per day, or the opening hours of a store per week.
+
+use std::collections::BTreeMap;
+use std::fmt;
+
+use business::{Weekday, WEEKDAYS};
+use duration::Duration;
+use error::Error;
+use join::{join_human, JoinOptions};
+
+const MINUTES_PER_DAY: usize = 24 * 60;
+
+/// Reads a time of day as minutes since midnight, ex) "9:30", "17:00", "9am" or "5:30 p.m.".
+/// "24:00" is accepted as the midnight ending a day.
+fn parse_time_of_day(s: &str) -> Result<usize, Error> {
+    let s = s.trim().replace('.', "");
+    let (clock, meridiem) = if let Some(clock) = s.strip_suffix("am") {
+        (clock.trim_end(), Some(0))
+    } else if let Some(clock) = s.strip_suffix("pm") {
+        (clock.trim_end(), Some(12))
+    } else {
+        (s.as_str(), None)
+    };
+
+    let (hours, minutes) = match clock.split_once(':') {
+        Some((hours, minutes)) if minutes.len() == 2 => (hours, minutes),
+        Some(_) => return Err(Error::InvalidFormat),
+        None => (clock, "0"),
+    };
+    let digits = |s: &str| !s.is_empty() && s.len() <= 2 && s.chars().all(|c| c.is_ascii_digit());
+    if !digits(hours) || !digits(minutes) {
+        return Err(Error::InvalidFormat);
+    }
+    let (hours, minutes): (usize, usize) = (hours.parse().unwrap(), minutes.parse().unwrap());
+
+    let hours = match meridiem {
+        Some(offset) if (1..=12).contains(&hours) => hours % 12 + offset,
+        Some(_) => return Err(Error::InvalidFormat),
+        None if hours == 24 && minutes == 0 => 24,
+        None if hours < 24 => hours,
+        None => return Err(Error::InvalidFormat),
+    };
+    if minutes >= 60 {
+        return Err(Error::InvalidFormat);
+    }
+    Ok(hours * 60 + minutes)
+}
+
+/// Parses a range of two times of day and returns the time elapsed between them, ex) "9:30–17:00"
+/// or "9am to 5:30pm" is 7 hours and 30 minutes. The times may be separated by a hyphen, an en or
+/// em dash, "to", "until" or "till", and use either the 24-hour clock or "am"/"pm". When the end
+/// precedes the start, the range wraps past midnight, ex) "22:00-6:00" is 8 hours.
+pub fn parse_time_range(s: &str) -> Result<Duration, Error> {
+    let s = s.to_lowercase().replace(['–', '—'], "-");
+    let (start, end) = ["-", " to ", " until ", " till "]
+        .iter()
+        .find_map(|sep| s.split_once(sep))
+        .ok_or(Error::InvalidFormat)?;
+    let (start, end) = (parse_time_of_day(start)?, parse_time_of_day(end)?);
+    let minutes = (end + MINUTES_PER_DAY - start % MINUTES_PER_DAY) % MINUTES_PER_DAY;
+    let minutes = if minutes == 0 && end > start { MINUTES_PER_DAY } else { minutes };
+    Ok(Duration::new(minutes * 60))
+}
+
+/// The time booked under one key of a [`TimeSheet`](struct.TimeSheet.html), split at the daily
+/// cap.
+#[derive(PartialEq, Clone, Copy)]
+pub struct Booked {
+    pub total: Duration,
+    /// The part of `total` up to the daily cap.
+    pub regular: Duration,
+    /// The part of `total` beyond the daily cap.
+    pub overtime: Duration,
+}
+
+/// Sums durations per key, ex) per day or per project, optionally counting the time beyond a
+/// daily cap as overtime.
+#[derive(Clone)]
+pub struct TimeSheet<K: Ord> {
+    secs: BTreeMap<K, usize>,
+    cap: Option<usize>,
+}
+
+impl<K: Ord> Default for TimeSheet<K> {
+    fn default() -> Self {
+        TimeSheet {
+            secs: BTreeMap::new(),
+            cap: None,
+        }
+    }
+}
+
+impl<K: Ord> TimeSheet<K> {
+    pub fn new() -> Self {
+        TimeSheet::default()
+    }
+
+    /// Splits the time of each key at `cap` into regular time and overtime.
+    pub fn daily_cap(mut self, cap: Duration) -> Self {
+        self.cap = Some(cap.total_seconds());
+        self
+    }
+
+    /// Books `d` under `key`, ex) a day, saturating.
+    pub fn add(&mut self, key: K, d: Duration) {
+        let secs = self.secs.entry(key).or_insert(0);
+        *secs = secs.saturating_add(d.total_seconds());
+    }
+
+    fn split(&self, secs: usize) -> Booked {
+        let regular = self.cap.map_or(secs, |cap| secs.min(cap));
+        Booked {
+            total: Duration::new(secs),
+            regular: Duration::new(regular),
+            overtime: Duration::new(secs - regular),
+        }
+    }
+
+    /// The time booked under `key`, zero if none was.
+    pub fn get(&self, key: &K) -> Booked {
+        self.split(self.secs.get(key).cloned().unwrap_or(0))
+    }
+
+    /// The time booked under each key, ordered by key.
+    pub fn iter(&self) -> impl Iterator<Item = (&K, Booked)> {
+        self.secs.iter().map(move |(key, &secs)| (key, self.split(secs)))
+    }
+
+    /// The sums over every key. Overtime is summed per key, so that the cap applies to each day
+    /// rather than to the whole sheet.
+    pub fn total(&self) -> Booked {
+        let mut secs = [0usize; 3];
+        for (_, booked) in self.iter() {
+            let parts = [booked.total, booked.regular, booked.overtime];
+            for (sum, part) in secs.iter_mut().zip(parts.iter()) {
+                *sum = sum.saturating_add(part.total_seconds());
+            }
+        }
+        Booked {
+            total: Duration::new(secs[0]),
+            regular: Duration::new(secs[1]),
+            overtime: Duration::new(secs[2]),
+        }
+    }
+}
+
+impl fmt::Display for Booked {
+    /// Formats in compact form with any overtime, ex) "9h 30m (1h 30m overtime)".
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.total.format_compact())?;
+        if !self.overtime.is_zero() {
+            write!(f, " ({} overtime)", self.overtime.format_compact())?;
+        }
+        Ok(())
+    }
+}
+
+impl<K: Ord + fmt::Display> fmt::Display for TimeSheet<K> {
+    /// Formats one line per key followed by the total, ex) "mon: 9h 30m (1h 30m overtime)".
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (key, booked) in self.iter() {
+            writeln!(f, "{}: {}", key, booked)?;
+        }
+        write!(f, "total: {}", self.total())
+    }
+}
+
+/// The opening hours of a week summed per day, see [`weekly_hours`](fn.weekly_hours.html).
+#[derive(PartialEq, Clone, Copy)]
+pub struct WeeklyHours {
+    secs: [usize; 7],
+}
+
+/// Sums opening hours given as `(day, opens, closes)`, ex) `(Weekday::Friday, "18:00", "2am")`,
+/// with times read like [`parse_time_range`](fn.parse_time_range.html). A range closing past
+/// midnight counts its hours after midnight towards the next day, Sunday's towards Monday.
+/// Ranges are summed as given, so overlapping ones count twice.
+pub fn weekly_hours<'a, I>(ranges: I) -> Result<WeeklyHours, Error>
+where
+    I: IntoIterator<Item = (Weekday, &'a str, &'a str)>,
+{
+    let mut secs = [0; 7];
+    for (day, opens, closes) in ranges {
+        let index = WEEKDAYS.iter().position(|d| *d == day).unwrap();
+        let start = parse_time_of_day(opens)? % MINUTES_PER_DAY;
+        let open = parse_time_range(&format!("{}-{}", opens, closes))?.total_seconds() / 60;
+        let same_day = open.min(MINUTES_PER_DAY - start);
+        secs[index] += same_day * 60;
+        secs[(index + 1) % 7] += (open - same_day) * 60;
+    }
+    Ok(WeeklyHours { secs })
+}
+
+impl WeeklyHours {
+    /// The time open over the whole week.
+    pub fn total(&self) -> Duration {
+        Duration::new(self.secs.iter().sum())
+    }
+
+    /// The time open on `day`.
+    pub fn on(&self, day: Weekday) -> Duration {
+        Duration::new(self.secs[WEEKDAYS.iter().position(|d| *d == day).unwrap()])
+    }
+
+    /// The number of days with any opening hours.
+    pub fn open_days(&self) -> usize {
+        self.secs.iter().filter(|&&secs| secs > 0).count()
+    }
+}
+
+impl fmt::Display for WeeklyHours {
+    /// Formats the weekly total in hours and minutes with the days open, ex) "62 hours and 30
+    /// minutes a week over 6 days".
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let minutes = self.total().total_seconds() / 60;
+        let (hours, minutes) = (minutes / 60, minutes % 60);
+        let plural = |n: usize, unit: &str| format!("{} {}{}", n, unit, if n == 1 { "" } else { "s" });
+        let mut parts = Vec::new();
+        if hours > 0 {
+            parts.push(plural(hours, "hour"));
+        }
+        if minutes > 0 || hours == 0 {
+            parts.push(plural(minutes, "minute"));
+        }
+        let options = JoinOptions {
+            terminator: "",
+            ..JoinOptions::default()
+        };
+        let days = self.open_days();
+        write!(f, "{} a week over {}", join_human(parts, &options), plural(days, "day"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use business::Weekday;
+    use duration::Duration;
+    use error::Error;
+    use timesheet::{parse_time_range, weekly_hours, TimeSheet};
+
+    #[test]
+    fn test_parse_time_range() {
+        assert!(parse_time_range("9:30–17:00") == Ok(Duration::new(27_000)));
+        assert!(parse_time_range("9am to 5:30pm") == Ok(Duration::new(30_600)));
+        assert!(parse_time_range("9:30 AM until 5 P.M.") == Ok(Duration::new(27_000)));
+        assert!(parse_time_range("12am - 12pm") == Ok(Duration::new(43_200)));
+        assert!(parse_time_range("0:00-24:00") == Ok(Duration::new(86_400)));
+        assert!(parse_time_range("9:00 till 9:00") == Ok(Duration::new(0)));
+    }
+
+    #[test]
+    fn test_parse_time_range_wraps() {
+        assert!(parse_time_range("22:00-6:00") == Ok(Duration::new(28_800)));
+        assert!(parse_time_range("11pm to 1am") == Ok(Duration::new(7200)));
+    }
+
+    #[test]
+    fn test_parse_time_range_errors() {
+        assert!(parse_time_range("9:30") == Err(Error::InvalidFormat));
+        assert!(parse_time_range("25:00-26:00") == Err(Error::InvalidFormat));
+        assert!(parse_time_range("13pm-14pm") == Err(Error::InvalidFormat));
+        assert!(parse_time_range("9:5-10:00") == Err(Error::InvalidFormat));
+        assert!(parse_time_range("9:60-10:00") == Err(Error::InvalidFormat));
+    }
+
+    #[test]
+    fn test_time_sheet() {
+        let mut sheet = TimeSheet::new().daily_cap(Duration::new(8 * 3600));
+        sheet.add("mon", parse_time_range("9am to 12pm").unwrap());
+        sheet.add("mon", parse_time_range("12:30-18:30").unwrap());
+        sheet.add("tue", Duration::new(4 * 3600));
+
+        let mon = sheet.get(&"mon");
+        assert!(mon.total == Duration::new(9 * 3600));
+        assert!(mon.overtime == Duration::new(3600));
+        assert!(sheet.get(&"wed").total.is_zero());
+        assert!(sheet.total().regular == Duration::new(12 * 3600));
+        assert!(sheet.to_string() == "mon: 9h (1h overtime)\ntue: 4h\ntotal: 13h (1h overtime)");
+    }
+
+    #[test]
+    fn test_time_sheet_without_cap() {
+        let mut sheet = TimeSheet::new();
+        sheet.add(1, Duration::new(20 * 3600));
+        assert!(sheet.get(&1).overtime.is_zero());
+        assert!(sheet.to_string() == "1: 20h\ntotal: 20h");
+    }
+
+    #[test]
+    fn test_weekly_hours() {
+        let mut ranges = vec![(Weekday::Monday, "9:00", "17:30")];
+        let midweek = [Weekday::Tuesday, Weekday::Wednesday, Weekday::Thursday];
+        ranges.extend(midweek.iter().map(|&day| (day, "9am", "5:30pm")));
+        ranges.push((Weekday::Friday, "18:00", "2am"));
+        ranges.push((Weekday::Sunday, "22:00", "24:00"));
+        let hours = weekly_hours(ranges).unwrap();
+        assert!(hours.total() == Duration::new((4 * 510 + 480 + 120) * 60));
+        assert!(hours.on(Weekday::Friday) == Duration::new(6 * 3600));
+        assert!(hours.on(Weekday::Saturday) == Duration::new(2 * 3600));
+        assert!(hours.on(Weekday::Sunday) == Duration::new(2 * 3600));
+        assert!(hours.on(Weekday::Monday) == Duration::new(510 * 60));
+        assert!(hours.open_days() == 7);
+        assert!(hours.to_string() == "44 hours a week over 7 days");
+
+        let late = weekly_hours(vec![(Weekday::Sunday, "23:30", "0:30")]).unwrap();
+        assert!(late.on(Weekday::Monday) == Duration::new(1800));
+        assert!(late.to_string() == "1 hour a week over 2 days");
+        assert!(weekly_hours(vec![]).unwrap().to_string() == "0 minutes a week over 0 days");
+        assert!(weekly_hours(vec![(Weekday::Monday, "9", "25:00")]) == Err(Error::InvalidFormat));
+    }
+}
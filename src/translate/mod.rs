@@ -0,0 +1,94 @@
+//! A process-wide translation hook for teams with gettext catalogs, as a lighter alternative to
+//! a full [`Locale`](../locale/struct.Locale.html), ex) forwarding to `ngettext`.
+
+use std::borrow::Cow;
+use std::sync::RwLock;
+
+use duration::Duration;
+use join::{join_human, JoinOptions};
+
+/// Translates `msgid` for the amount `n`, the way `ngettext` picks a plural form. The msgids are
+/// the singular unit names "second", "minute", "hour", "day" and "year" with the amount, and
+/// "and" and ", " with 1.
+pub type Translator = fn(msgid: &str, n: usize) -> Cow<'static, str>;
+
+static TRANSLATOR: RwLock<Option<Translator>> = RwLock::new(None);
+
+/// Installs `translator` across the process for
+/// [`format_translated`](../duration/struct.Duration.html#method.format_translated), replacing
+/// any previous one.
+pub fn set_translator(translator: Translator) {
+    *TRANSLATOR.write().unwrap_or_else(|e| e.into_inner()) = Some(translator);
+}
+
+/// Removes the translator, restoring English.
+pub fn clear_translator() {
+    *TRANSLATOR.write().unwrap_or_else(|e| e.into_inner()) = None;
+}
+
+/// Translates `msgid` with the installed translator, or returns `english` without one.
+fn translate(msgid: &'static str, n: usize, english: &'static str) -> Cow<'static, str> {
+    match *TRANSLATOR.read().unwrap_or_else(|e| e.into_inner()) {
+        Some(translator) => translator(msgid, n),
+        None => Cow::Borrowed(english),
+    }
+}
+
+impl Duration {
+    /// Formats `self` like `Display` without the period, with every word passed through the
+    /// translator of [`set_translator`](../translate/fn.set_translator.html), ex) "2 Stunden
+    /// und 30 Minuten". Without a translator, the output is English.
+    pub fn format_translated(&self) -> String {
+        let items = self.iter_units().map(|unit| {
+            let english = if unit.amount == 1 { unit.kind.name() } else { unit.kind.plural_name() };
+            format!("{} {}", unit.amount, translate(unit.kind.name(), unit.amount, english))
+        });
+        let items = items.collect::<Vec<String>>();
+        if items.is_empty() {
+            return format!("0 {}", translate("second", 0, "seconds"));
+        }
+        let separator = translate(", ", 1, ", ");
+        let conjunction = translate("and", 1, "and");
+        let options = JoinOptions {
+            separator: &separator,
+            conjunction: &conjunction,
+            serial_comma: false,
+            terminator: "",
+        };
+        join_human(items, &options)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::borrow::Cow;
+
+    use duration::Duration;
+    use translate::{clear_translator, set_translator};
+
+    fn german(msgid: &str, n: usize) -> Cow<'static, str> {
+        let (singular, plural) = match msgid {
+            "second" => ("Sekunde", "Sekunden"),
+            "minute" => ("Minute", "Minuten"),
+            "hour" => ("Stunde", "Stunden"),
+            "day" => ("Tag", "Tage"),
+            "year" => ("Jahr", "Jahre"),
+            "and" => ("und", "und"),
+            _ => return Cow::Owned(msgid.to_string()),
+        };
+        Cow::Borrowed(if n == 1 { singular } else { plural })
+    }
+
+    #[test]
+    fn test_format_translated() {
+        let d = Duration::new(93_660);
+        assert!(d.format_translated() == "1 day, 2 hours and 1 minute");
+        assert!(Duration::ZERO.format_translated() == "0 seconds");
+
+        set_translator(german);
+        assert!(d.format_translated() == "1 Tag, 2 Stunden und 1 Minute");
+        assert!(Duration::ZERO.format_translated() == "0 Sekunden");
+        clear_translator();
+        assert!(Duration::new(9000).format_translated() == "2 hours and 30 minutes");
+    }
+}
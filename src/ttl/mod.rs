@@ -0,0 +1,215 @@
+//! Conversions between durations and the TTLs of caches, key-value stores and tokens, ex) Redis
+//! or the `expires_in` of an OAuth token response.
+
+use std::convert::TryFrom;
+use std::fmt;
+use std::time::{Duration as StdDuration, SystemTime, UNIX_EPOCH};
+
+use duration::Duration;
+
+/// The largest TTL in seconds Redis accepts, as it keeps expiry times in milliseconds as `i64`.
+const MAX_REDIS_TTL: u64 = i64::MAX as u64 / 1000;
+
+/// The reply of a Redis `TTL` command.
+#[derive(PartialEq, Clone, Copy)]
+pub enum RedisTtl {
+    /// The key does not exist, replied as -2.
+    Missing,
+    /// The key exists without an expiry, replied as -1.
+    Persistent,
+    /// The key expires after the duration.
+    Expires(Duration),
+}
+
+/// A creation time along with a TTL, ex) a cache entry or an access token.
+#[derive(PartialEq, Clone, Copy)]
+pub struct Expiry {
+    pub created: SystemTime,
+    pub ttl: Duration,
+}
+
+impl Expiry {
+    pub fn new(created: SystemTime, ttl: Duration) -> Self {
+        Expiry { created, ttl }
+    }
+
+    /// Starts the TTL now.
+    pub fn from_now(ttl: Duration) -> Self {
+        Expiry::new(SystemTime::now(), ttl)
+    }
+
+    /// The time of expiry, `None` if it cannot be represented, in which case it never expires.
+    pub fn expires_at(&self) -> Option<SystemTime> {
+        self.ttl.expires_at(self.created)
+    }
+
+    pub fn is_expired(&self) -> bool {
+        self.is_expired_at(SystemTime::now())
+    }
+
+    /// Whether the TTL has elapsed by `now`.
+    pub fn is_expired_at(&self, now: SystemTime) -> bool {
+        self.expires_at().is_some_and(|at| now >= at)
+    }
+
+    pub fn remaining(&self) -> Duration {
+        self.remaining_at(SystemTime::now())
+    }
+
+    /// The time left at `now` in whole seconds, zero once expired.
+    pub fn remaining_at(&self, now: SystemTime) -> Duration {
+        match self.expires_at() {
+            Some(at) => at.duration_since(now).map_or(Duration::ZERO, saturating_secs),
+            None => Duration::MAX,
+        }
+    }
+
+    /// The status at `now`, ex) "expires in 5 minutes" or "expired 2 hours ago", in the largest
+    /// unit rounded to the nearest amount. A sub-second remainder counts as a second left.
+    pub fn status_at(&self, now: SystemTime) -> String {
+        let at = match self.expires_at() {
+            Some(at) => at,
+            None => return self.ttl.format_expires_in(),
+        };
+        match at.duration_since(now) {
+            Ok(left) if left > StdDuration::ZERO => {
+                let secs = left.as_secs() + if left.subsec_nanos() > 0 { 1 } else { 0 };
+                saturating_secs(StdDuration::from_secs(secs)).format_expires_in()
+            }
+            _ => {
+                let ago = now.duration_since(at).map_or(Duration::ZERO, saturating_secs);
+                match ago.approximate_unit() {
+                    Some(unit) => format!("expired {} ago", unit),
+                    None => "expired".to_string(),
+                }
+            }
+        }
+    }
+}
+
+/// Formats the status at the current time, see
+/// [`status_at`](struct.Expiry.html#method.status_at).
+impl fmt::Display for Expiry {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.pad(&self.status_at(SystemTime::now()))
+    }
+}
+
+fn saturating_secs(d: StdDuration) -> Duration {
+    Duration::new(usize::try_from(d.as_secs()).unwrap_or(usize::MAX))
+}
+
+impl Duration {
+    /// `self` in whole seconds for `EXPIRE`, `SETEX` or `SET ... EX`, clamped to at least 1,
+    /// since Redis rejects a TTL of 0, and at most what Redis accepts.
+    pub fn as_redis_ttl(&self) -> u64 {
+        (self.total_seconds() as u64).clamp(1, MAX_REDIS_TTL)
+    }
+
+    /// Reads the reply of a Redis `TTL` command. Negative replies other than -1 are taken as
+    /// `Missing`.
+    pub fn from_redis_ttl(reply: i64) -> RedisTtl {
+        match reply {
+            -1 => RedisTtl::Persistent,
+            secs if secs < 0 => RedisTtl::Missing,
+            secs => RedisTtl::Expires(Duration::new(secs as usize)),
+        }
+    }
+
+    /// The Unix timestamp for `EXPIREAT` at which a key expires `self` after `now`. The sub-second
+    /// part of `now` rounds up, so that the key never expires before the TTL has elapsed.
+    pub fn redis_expire_at(&self, now: SystemTime) -> i64 {
+        let now = match now.duration_since(UNIX_EPOCH) {
+            Ok(d) => d.as_secs() as i64 + if d.subsec_nanos() > 0 { 1 } else { 0 },
+            Err(e) => -(e.duration().as_secs() as i64),
+        };
+        now.saturating_add(self.as_redis_ttl() as i64)
+    }
+
+    /// Reads the `expires_in` seconds of an OAuth token response or a JWT derived lifetime.
+    /// Negative values, sent by clock-skewed servers for tokens already expired, yield zero.
+    pub fn from_expires_in(secs: i64) -> Duration {
+        Duration::new(secs.max(0) as usize)
+    }
+
+    /// The time at which a token issued at `issued` with a lifetime of `self` expires. Returns
+    /// `None` if that time cannot be represented.
+    pub fn expires_at(&self, issued: SystemTime) -> Option<SystemTime> {
+        issued.checked_add(StdDuration::from_secs(self.total_seconds() as u64))
+    }
+
+    /// Formats `self` as the time left before an expiry, in its largest unit rounded to the
+    /// nearest amount, ex) "expires in 58 minutes". Zero formats as "expired".
+    pub fn format_expires_in(&self) -> String {
+        match self.approximate_unit() {
+            Some(unit) => format!("expires in {}", unit),
+            None => "expired".to_string(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::{Duration as StdDuration, UNIX_EPOCH};
+
+    use duration::Duration;
+    use ttl::{Expiry, RedisTtl};
+
+    #[test]
+    fn test_as_redis_ttl() {
+        assert!(Duration::new(90).as_redis_ttl() == 90);
+        assert!(Duration::new(0).as_redis_ttl() == 1);
+        assert!(Duration::MAX.as_redis_ttl() == i64::MAX as u64 / 1000);
+    }
+
+    #[test]
+    fn test_from_redis_ttl() {
+        assert!(Duration::from_redis_ttl(-2) == RedisTtl::Missing);
+        assert!(Duration::from_redis_ttl(-1) == RedisTtl::Persistent);
+        assert!(Duration::from_redis_ttl(3600) == RedisTtl::Expires(Duration::new(3600)));
+    }
+
+    #[test]
+    fn test_expires_in() {
+        let lifetime = Duration::from_expires_in(3480);
+        assert!(lifetime == Duration::new(3480));
+        assert!(lifetime.format_expires_in() == "expires in 58 minutes");
+        assert!(Duration::from_expires_in(-30).is_zero());
+        assert!(Duration::from_expires_in(-30).format_expires_in() == "expired");
+
+        let issued = UNIX_EPOCH + StdDuration::from_secs(1_700_000_000);
+        assert!(lifetime.expires_at(issued) == Some(UNIX_EPOCH + StdDuration::from_secs(1_700_003_480)));
+        assert!(Duration::MAX.expires_at(issued).is_none());
+    }
+
+    #[test]
+    fn test_redis_expire_at() {
+        let now = UNIX_EPOCH + StdDuration::from_millis(1_700_000_000_250);
+        assert!(Duration::new(60).redis_expire_at(now) == 1_700_000_061);
+        let now = UNIX_EPOCH + StdDuration::from_secs(1_700_000_000);
+        assert!(Duration::new(0).redis_expire_at(now) == 1_700_000_001);
+    }
+
+    #[test]
+    fn test_expiry() {
+        let created = UNIX_EPOCH + StdDuration::from_secs(1_700_000_000);
+        let expiry = Expiry::new(created, Duration::new(600));
+        let at = |secs: u64| created + StdDuration::from_secs(secs);
+        assert!(expiry.expires_at() == Some(at(600)));
+
+        assert!(!expiry.is_expired_at(at(300)));
+        assert!(expiry.remaining_at(at(300)) == Duration::new(300));
+        assert!(expiry.status_at(at(300)) == "expires in 5 minutes");
+
+        assert!(expiry.is_expired_at(at(600)));
+        assert!(expiry.remaining_at(at(600)).is_zero());
+        assert!(expiry.status_at(at(600)) == "expired");
+        assert!(expiry.status_at(at(600 + 7000)) == "expired 2 hours ago");
+        assert!(expiry.remaining_at(at(7600)).is_zero());
+
+        let forever = Expiry::new(created, Duration::MAX);
+        assert!(!forever.is_expired_at(at(600)));
+        assert!(forever.remaining_at(at(600)) == Duration::MAX);
+        assert!(Expiry::from_now(Duration::new(60)).to_string() == "expires in 1 minute");
+    }
+}
@@ -0,0 +1,150 @@
+//! Decomposition and formatting over custom units, ex) sols on Mars or ticks and turns in a
+//! game, reusing the rules of [`Duration`](../duration/struct.Duration.html)'s `Display` and
+//! [`format_compact`](../duration/struct.Duration.html#method.format_compact).
+
+use duration::Duration;
+use join::{join_human, JoinOptions};
+
+/// One unit of a [`UnitSystem`](trait.UnitSystem.html).
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub struct Unit {
+    pub singular: &'static str,
+    pub plural: &'static str,
+    /// The abbreviation used in compact form, ex) "h".
+    pub symbol: &'static str,
+    /// The length of the unit in base units, ex) seconds or ticks. Must not be zero.
+    pub base: u64,
+}
+
+/// An ordered list of units which amounts of a base unit decompose into.
+pub trait UnitSystem {
+    /// The units ordered from the largest to the smallest. Amounts below the smallest unit are
+    /// dropped, so it usually has a `base` of 1.
+    fn units(&self) -> &[Unit];
+}
+
+/// The units of [`Duration`](../duration/struct.Duration.html), in seconds.
+#[derive(PartialEq, Eq, Clone, Copy, Debug, Default)]
+pub struct Earth;
+
+static EARTH: [Unit; 5] = [
+    Unit { singular: "year", plural: "years", symbol: "y", base: 365 * 24 * 60 * 60 },
+    Unit { singular: "day", plural: "days", symbol: "d", base: 24 * 60 * 60 },
+    Unit { singular: "hour", plural: "hours", symbol: "h", base: 60 * 60 },
+    Unit { singular: "minute", plural: "minutes", symbol: "m", base: 60 },
+    Unit { singular: "second", plural: "seconds", symbol: "s", base: 1 },
+];
+
+impl UnitSystem for Earth {
+    fn units(&self) -> &[Unit] {
+        &EARTH
+    }
+}
+
+/// Splits `amount` base units into units of `system`, largest first, ex) 90 seconds into 1
+/// minute and 30 seconds. Every unit is listed, including those of amount zero.
+pub fn decompose<U: UnitSystem + ?Sized>(system: &U, amount: u64) -> Vec<(&Unit, u64)> {
+    let mut rest = amount;
+    system
+        .units()
+        .iter()
+        .map(|unit| {
+            let n = rest / unit.base;
+            rest %= unit.base;
+            (unit, n)
+        })
+        .collect()
+}
+
+/// Formats `amount` base units as prose, ex) "1 sol, 2 hours and 5 seconds.". Zero formats with
+/// the smallest unit, ex) "0 ticks.".
+pub fn format_prose<U: UnitSystem + ?Sized>(system: &U, amount: u64) -> String {
+    let parts = decompose(system, amount)
+        .into_iter()
+        .filter(|&(_, n)| n > 0)
+        .map(|(unit, n)| format!("{} {}", n, if n == 1 { unit.singular } else { unit.plural }))
+        .collect::<Vec<String>>();
+    if parts.is_empty() {
+        let smallest = system.units().last().map_or("", |unit| unit.plural);
+        return format!("0 {}.", smallest);
+    }
+    join_human(parts, &JoinOptions::default())
+}
+
+/// Formats `amount` base units in compact form, ex) "1sol 2h 5s". Zero formats with the
+/// smallest unit, ex) "0t".
+pub fn format_compact<U: UnitSystem + ?Sized>(system: &U, amount: u64) -> String {
+    let parts = decompose(system, amount)
+        .into_iter()
+        .filter(|&(_, n)| n > 0)
+        .map(|(unit, n)| format!("{}{}", n, unit.symbol))
+        .collect::<Vec<String>>();
+    if parts.is_empty() {
+        let smallest = system.units().last().map_or("", |unit| unit.symbol);
+        return format!("0{}", smallest);
+    }
+    parts.join(" ")
+}
+
+impl Duration {
+    /// Formats `self` as prose in the units of `system`, whose base unit must be the second, ex)
+    /// "1 sol and 2 hours." for a system of Martian sols.
+    pub fn format_in<U: UnitSystem + ?Sized>(&self, system: &U) -> String {
+        format_prose(system, self.total_seconds() as u64)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use duration::Duration;
+    use units::{decompose, format_compact, format_prose, Earth, Unit, UnitSystem};
+
+    struct Mars;
+
+    static MARS: [Unit; 3] = [
+        Unit { singular: "sol", plural: "sols", symbol: "sol", base: 88_775 },
+        Unit { singular: "hour", plural: "hours", symbol: "h", base: 3600 },
+        Unit { singular: "second", plural: "seconds", symbol: "s", base: 1 },
+    ];
+
+    impl UnitSystem for Mars {
+        fn units(&self) -> &[Unit] {
+            &MARS
+        }
+    }
+
+    struct Game;
+
+    static GAME: [Unit; 2] = [
+        Unit { singular: "turn", plural: "turns", symbol: "T", base: 20 },
+        Unit { singular: "tick", plural: "ticks", symbol: "t", base: 1 },
+    ];
+
+    impl UnitSystem for Game {
+        fn units(&self) -> &[Unit] {
+            &GAME
+        }
+    }
+
+    #[test]
+    fn test_earth_matches_duration() {
+        for &secs in &[1, 61, 7199, 3_600 * 24 * 400 + 5] {
+            let d = Duration::new(secs);
+            assert!(d.format_in(&Earth) == d.to_string());
+            assert!(format_compact(&Earth, secs as u64) == d.format_compact());
+        }
+        assert!(format_compact(&Earth, 0) == Duration::new(0).format_compact());
+    }
+
+    #[test]
+    fn test_custom_systems() {
+        assert!(Duration::new(88_775 + 7205).format_in(&Mars) == "1 sol, 2 hours and 5 seconds.");
+        assert!(format_compact(&Mars, 88_775 * 2) == "2sol");
+        assert!(format_prose(&Game, 41) == "2 turns and 1 tick.");
+        assert!(format_prose(&Game, 0) == "0 ticks.");
+        assert!(format_compact(&Game, 19) == "19t");
+
+        let parts = decompose(&Game, 41);
+        assert!(parts.iter().map(|&(unit, n)| (unit.symbol, n)).collect::<Vec<_>>() == [("T", 2), ("t", 1)]);
+    }
+}